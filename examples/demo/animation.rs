@@ -1,3 +1,5 @@
+use core::fmt::{self, Write};
+
 use rmicrobit::display::Render;
 use rmicrobit::graphics::image::GreyscaleImage;
 use rmicrobit::graphics::scrolling::{Animate, ScrollingImages};
@@ -191,9 +193,11 @@ pub struct ScrollingBufferedTextAnimator {
 
 impl ScrollingBufferedTextAnimator {
 
-    pub fn reset(&mut self, message: &[u8], slowdown: usize) {
+    pub fn reset(&mut self, message: fmt::Arguments, slowdown: usize) {
         self.ticker.reset(slowdown);
-        self.scroller.set_message(message);
+        self.scroller.clear_message();
+        // a message too long for the buffer is displayed truncated
+        self.scroller.write_fmt(message).ok();
     }
 
 }
@@ -1,8 +1,8 @@
-use numtoa::NumToA;
-
 use rmicrobit::display::{Render, MAX_BRIGHTNESS};
+use rmicrobit::graphics::draw::Draw;
 use rmicrobit::graphics::font;
 use rmicrobit::graphics::image::{GreyscaleImage, BitImage};
+use rmicrobit::graphics::images;
 use rmicrobit::buttons::dual_with_hold::ButtonEvent;
 
 use crate::animation::{
@@ -45,38 +45,36 @@ const ONE_TWO: GreyscaleImage = GreyscaleImage::new(&[
 ]);
 
 fn chequer_image(brightness: u8) -> GreyscaleImage {
-    let b = brightness;
-    let w = MAX_BRIGHTNESS as u8 - brightness;
-    GreyscaleImage::new(&[
-        [b, w, b, w, b],
-        [w, b, w, b, w],
-        [b, w, b, w, b],
-        [w, b, w, b, w],
-        [b, w, b, w, b],
-    ])
+    let mut image = GreyscaleImage::filled(MAX_BRIGHTNESS as u8 - brightness);
+    // pixels with x + y even
+    for i in (0..25).step_by(2) {
+        image.plot(i % 5, i / 5, brightness);
+    }
+    image
 }
 
 fn stripe_image(brightness: u8) -> GreyscaleImage {
-    let even = brightness;
-    let odd = MAX_BRIGHTNESS as u8 - brightness;
-    GreyscaleImage::new(&[
-        [even; 5],
-        [odd; 5],
-        [even; 5],
-        [odd; 5],
-        [even; 5],
-    ])
+    let mut image = GreyscaleImage::filled(MAX_BRIGHTNESS as u8 - brightness);
+    for y in (0..5).step_by(2) {
+        image.line(0, y, 4, y, brightness);
+    }
+    image
 }
 
+// A heart whose outline has brightness 7 and whose inside (the small heart)
+// has brightness `brightness`.
 fn heart_image(brightness: u8) -> GreyscaleImage {
-    let b = brightness;
-    GreyscaleImage::new(&[
-        [0, 7, 0, 7, 0],
-        [7, b, 7, b, 7],
-        [7, b, b, b, 7],
-        [0, 7, b, 7, 0],
-        [0, 0, 7, 0, 0],
-    ])
+    let mut image = GreyscaleImage::blank();
+    for y in 0..5 {
+        for x in 0..5 {
+            if images::HEART_SMALL.pixel(x, y) {
+                image.set_pixel(x, y, brightness);
+            } else if images::HEART.pixel(x, y) {
+                image.set_pixel(x, y, 7);
+            }
+        }
+    }
+    image
 }
 
 fn render_chequers(frame_index: usize) -> GreyscaleImage {
@@ -217,10 +215,7 @@ impl Demo {
     fn set_counter(&mut self, value: usize) {
         self.counter_index = value;
         let slowdown: usize = 2;
-        let mut buffer = [0; 12];
-        self.sbt_animator.reset(
-            self.counter_index.numtoa(10, &mut buffer),
-            slowdown);
+        self.sbt_animator.reset(format_args!("{}", self.counter_index), slowdown);
     }
 
     fn reset_current_state(&mut self) {
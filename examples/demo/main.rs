@@ -6,7 +6,7 @@ extern crate panic_semihosting;
 use rtfm::app;
 use rmicrobit::nrf51;
 use rmicrobit::prelude::*;
-use rmicrobit::display::{DisplayPort, MicrobitDisplay, MicrobitFrame};
+use rmicrobit::display::{DisplayPort, MicrobitDisplay};
 use rmicrobit::gpio::PinsByKind;
 use rmicrobit::nrf51_hal::lo_res_timer::{LoResTimer, FREQ_16HZ};
 use rmicrobit::buttons;
@@ -37,9 +37,7 @@ const APP: () = {
         let mut display = MicrobitDisplay::new(display_port, p.TIMER1);
         let button_monitor = ABMonitor::new(button_a, button_b);
 
-        let mut frame = MicrobitFrame::const_default();
-        frame.set(demo::initial_frame());
-        display.set_frame(&frame);
+        display.set_image(demo::initial_frame());
 
         // Starting the low-frequency clock (needed for RTC to work)
         p.CLOCK.tasks_lfclkstart.write(|w| unsafe { w.bits(1) });
@@ -74,13 +72,12 @@ const APP: () = {
     #[task(priority = 1,
            resources = [display, button_monitor, demo])]
     fn handle_buttons(mut cx: handle_buttons::Context) {
-        static mut FRAME: MicrobitFrame = MicrobitFrame::const_default();
         if let Some(event) = cx.resources.button_monitor.poll() {
             cx.resources.demo.handle_button_event(event);
             if cx.resources.demo.is_static() {
-                FRAME.set(cx.resources.demo.current_image());
+                let image = cx.resources.demo.current_image();
                 cx.resources.display.lock(|display| {
-                    display.set_frame(FRAME);
+                    display.set_image(image);
                 });
             }
         }
@@ -89,19 +86,18 @@ const APP: () = {
     #[task(binds = RTC0, priority = 1,
            resources = [anim_timer, display, demo])]
     fn rtc0(mut cx: rtc0::Context) {
-        static mut FRAME: MicrobitFrame = MicrobitFrame::const_default();
-
         cx.resources.anim_timer.clear_tick_event();
         if cx.resources.demo.is_animating() {
-            FRAME.set(&cx.resources.demo.next_animation_frame());
+            let image = cx.resources.demo.next_animation_frame();
+            cx.resources.display.lock(|display| {
+                display.set_image(&image);
+            });
         } else if cx.resources.demo.is_scrolling() {
-            FRAME.set(cx.resources.demo.next_scrolling_frame());
-        } else {
-            return
+            let image = cx.resources.demo.next_scrolling_frame();
+            cx.resources.display.lock(|display| {
+                display.set_image(image);
+            });
         }
-        cx.resources.display.lock(|display| {
-            display.set_frame(FRAME);
-        });
     }
 
     // Interrupt handlers used to dispatch software tasks
@@ -7,19 +7,25 @@ use rtfm::app;
 use rmicrobit::nrf51;
 use rmicrobit::nrf51_hal::lo_res_timer::{LoResTimer, FREQ_16HZ};
 use rmicrobit::prelude::*;
-use rmicrobit::display::{DisplayPort, MicrobitDisplay, MicrobitFrame};
+use rmicrobit::display::{DisplayPort, MicrobitDisplay};
 use rmicrobit::gpio::PinsByKind;
 use rmicrobit::graphics::image::GreyscaleImage;
+use rmicrobit::graphics::images;
 
+// A heart whose outline has brightness 7 and whose inside (the small heart)
+// has brightness `inner_brightness`.
 fn heart_image(inner_brightness: u8) -> GreyscaleImage {
-    let b = inner_brightness;
-    GreyscaleImage::new(&[
-        [0, 7, 0, 7, 0],
-        [7, b, 7, b, 7],
-        [7, b, b, b, 7],
-        [0, 7, b, 7, 0],
-        [0, 0, 7, 0, 0],
-    ])
+    let mut image = GreyscaleImage::blank();
+    for y in 0..5 {
+        for x in 0..5 {
+            if images::HEART_SMALL.pixel(x, y) {
+                image.set_pixel(x, y, inner_brightness);
+            } else if images::HEART.pixel(x, y) {
+                image.set_pixel(x, y, 7);
+            }
+        }
+    }
+    image
 }
 
 #[app(device = rmicrobit::nrf51, peripherals = true)]
@@ -65,7 +71,6 @@ const APP: () = {
     #[task(binds = RTC0, priority = 1,
            resources = [anim_timer, display])]
     fn rtc0(mut cx: rtc0::Context) {
-        static mut FRAME: MicrobitFrame = MicrobitFrame::const_default();
         static mut STEP: u8 = 0;
 
         cx.resources.anim_timer.clear_tick_event();
@@ -76,9 +81,9 @@ const APP: () = {
             _ => unreachable!()
         };
 
-        FRAME.set(&heart_image(inner_brightness));
+        let image = heart_image(inner_brightness);
         cx.resources.display.lock(|display| {
-            display.set_frame(FRAME);
+            display.set_image(&image);
         });
 
         *STEP += 1;
@@ -7,7 +7,7 @@ use rtfm::app;
 use rmicrobit::nrf51;
 use rmicrobit::nrf51_hal::lo_res_timer::{LoResTimer, FREQ_16HZ};
 use rmicrobit::prelude::*;
-use rmicrobit::display::{DisplayPort, MicrobitDisplay, MicrobitFrame};
+use rmicrobit::display::{DisplayPort, MicrobitDisplay};
 use rmicrobit::gpio::PinsByKind;
 use rmicrobit::graphics::scrolling_text::ScrollingStaticText;
 
@@ -61,13 +61,12 @@ const APP: () = {
     #[task(binds = RTC0, priority = 1,
            resources = [anim_timer, display, scroller])]
     fn rtc0(mut cx: rtc0::Context) {
-        static mut FRAME: MicrobitFrame = MicrobitFrame::const_default();
         cx.resources.anim_timer.clear_tick_event();
         if !cx.resources.scroller.is_finished() {
             cx.resources.scroller.tick();
-            FRAME.set(cx.resources.scroller);
+            let scroller = &*cx.resources.scroller;
             cx.resources.display.lock(|display| {
-                display.set_frame(FRAME);
+                display.set_image(scroller);
             });
         }
     }
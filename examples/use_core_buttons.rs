@@ -7,7 +7,7 @@ use rtfm::app;
 use rmicrobit::nrf51;
 use rmicrobit::prelude::*;
 use rmicrobit::display::{
-    DisplayPort, MicrobitDisplay, MicrobitFrame, Render};
+    DisplayPort, MicrobitDisplay, Render};
 use rmicrobit::graphics::font;
 use rmicrobit::gpio::PinsByKind;
 use rmicrobit::buttons;
@@ -61,9 +61,7 @@ const APP: () = {
         let mut display = MicrobitDisplay::new(display_port, p.TIMER1);
         let demo = DemoState{letter: b'-'};
 
-        let mut frame = MicrobitFrame::const_default();
-        frame.set(&demo.current_graphic());
-        display.set_frame(&frame);
+        display.set_image(&demo.current_graphic());
 
         init::LateResources {
             display : display,
@@ -86,7 +84,6 @@ const APP: () = {
     #[task(priority = 1,
            resources = [display, button_a, button_b, demo])]
     fn handle_buttons(mut cx: handle_buttons::Context) {
-        static mut FRAME: MicrobitFrame = MicrobitFrame::const_default();
         let button_a = cx.resources.button_a;
         let button_b = cx.resources.button_b;
         let mut invalidated = false;
@@ -99,9 +96,9 @@ const APP: () = {
             invalidated = true;
         }
         if invalidated {
-            FRAME.set(&cx.resources.demo.current_graphic());
+            let image = cx.resources.demo.current_graphic();
             cx.resources.display.lock(|display| {
-                display.set_frame(FRAME);
+                display.set_image(&image);
             });
         }
     }
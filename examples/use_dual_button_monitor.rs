@@ -9,7 +9,7 @@ use rtfm::app;
 use rmicrobit::nrf51;
 use rmicrobit::prelude::*;
 use rmicrobit::display::{
-    DisplayPort, MicrobitDisplay, MicrobitFrame, Render};
+    DisplayPort, MicrobitDisplay, Render};
 use rmicrobit::graphics::font;
 use rmicrobit::gpio::PinsByKind;
 use rmicrobit::buttons;
@@ -78,9 +78,7 @@ const APP: () = {
         let monitor = ABMonitor::new(button_a, button_b);
         let demo = DemoState{letter: b'-'};
 
-        let mut frame = MicrobitFrame::const_default();
-        frame.set(&demo.current_graphic());
-        display.set_frame(&frame);
+        display.set_image(&demo.current_graphic());
 
         init::LateResources {
             display : display,
@@ -102,12 +100,11 @@ const APP: () = {
     #[task(priority = 1,
            resources = [display, monitor, demo])]
     fn handle_buttons(mut cx: handle_buttons::Context) {
-        static mut FRAME: MicrobitFrame = MicrobitFrame::const_default();
         if let Some(event) = cx.resources.monitor.poll() {
             cx.resources.demo.handle(event);
-            FRAME.set(&cx.resources.demo.current_graphic());
+            let image = cx.resources.demo.current_graphic();
             cx.resources.display.lock(|display| {
-                display.set_frame(FRAME);
+                display.set_image(&image);
             });
         }
     }
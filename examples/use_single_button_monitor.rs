@@ -9,7 +9,7 @@ use rtfm::app;
 use rmicrobit::nrf51;
 use rmicrobit::prelude::*;
 use rmicrobit::display::{
-    DisplayPort, MicrobitDisplay, MicrobitFrame, Render};
+    DisplayPort, MicrobitDisplay, Render};
 use rmicrobit::graphics::font;
 use rmicrobit::gpio::PinsByKind;
 use rmicrobit::buttons;
@@ -68,9 +68,7 @@ const APP: () = {
         let monitor_b = ButtonBMonitor::new(button_b);
         let demo = DemoState{letter: b'-'};
 
-        let mut frame = MicrobitFrame::const_default();
-        frame.set(&demo.current_graphic());
-        display.set_frame(&frame);
+        display.set_image(&demo.current_graphic());
 
         init::LateResources {
             display : display,
@@ -93,7 +91,6 @@ const APP: () = {
     #[task(priority = 1,
            resources = [display, monitor_a, monitor_b, demo])]
     fn handle_buttons(mut cx: handle_buttons::Context) {
-        static mut FRAME: MicrobitFrame = MicrobitFrame::const_default();
         let monitor_a = cx.resources.monitor_a;
         let monitor_b = cx.resources.monitor_b;
         let mut invalidated = false;
@@ -107,9 +104,9 @@ const APP: () = {
             invalidated = true;
         }
         if invalidated {
-            FRAME.set(&cx.resources.demo.current_graphic());
+            let image = cx.resources.demo.current_graphic();
             cx.resources.display.lock(|display| {
-                display.set_frame(FRAME);
+                display.set_image(&image);
             });
         }
     }
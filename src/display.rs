@@ -69,6 +69,13 @@
 //! only [`MicrobitDisplay::set_frame()`][set_frame] has to be called in code
 //! that can't be interrupted by the display timer.
 //!
+//! # Orientation
+//!
+//! If the micro:bit is mounted rotated or upside down, use
+//! [`MicrobitDisplay::set_orientation()`] to say so. The orientation is
+//! applied by [`set_image()`] and [`MicrobitFrame::set_oriented()`], so the
+//! rest of the program can use the usual coordinate system.
+//!
 //! # Timer integration
 //!
 //! The `MicrobitDisplay` owns a single timer peripheral. It can use the
@@ -115,11 +122,20 @@
 //! You can call `set_frame()` at any time, so long as you're not
 //! interrupting, or interruptable by, `handle_event()`.
 //!
+//! Alternatively, call the `MicrobitDisplay`'s [`set_image()`] method, which
+//! creates the `MicrobitFrame` itself.
+//!
 //! Once you've called `set_frame()`, you are free to reuse the
 //! `MicrobitFrame`.
 //!
 //! See [`doc_example`] for a complete working example.
 //!
+//! # Simulation
+//!
+//! With the `sim` cargo feature enabled, the [`sim`] module provides a
+//! simulated display which can be used in tests running on the host
+//! machine.
+//!
 //! [dal]: https://lancaster-university.github.io/microbit-docs/
 //! [micropython]: https://microbit-micropython.readthedocs.io/
 //!
@@ -128,6 +144,7 @@
 //! [`graphics`]: crate::graphics
 //! [set_frame]: MicrobitDisplay::set_frame
 //! [`handle_event()`]: MicrobitDisplay::handle_event
+//! [`set_image()`]: MicrobitDisplay::set_image
 //!
 
 #[doc(no_inline)]
@@ -145,6 +162,9 @@ mod timer;
 
 pub mod doc_example;
 
+#[cfg(any(test, feature = "sim"))]
+pub mod sim;
+
 pub use display_port::{pin_constants, DisplayPort};
 pub use matrix::MicrobitFrame;
 pub use microbit_display::MicrobitDisplay;
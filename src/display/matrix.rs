@@ -6,8 +6,9 @@
 //! [`Matrix`]: tiny_led_matrix::Matrix
 //! [`Frame`]: tiny_led_matrix::Frame
 
-use tiny_led_matrix::{Frame, Matrix, RowPlan};
+use tiny_led_matrix::{Frame, Matrix, Render, RowPlan};
 use crate::display::display_port::pin_constants::{MATRIX_COLS, MATRIX_ROWS};
+use crate::graphics::transform::{Transform, Transformed};
 
 /// Implementation of [`Matrix`] for the microbit's LED display.
 ///
@@ -67,6 +68,21 @@ impl MicrobitFrame {
     pub const fn const_default() -> MicrobitFrame {
         MicrobitFrame([RowPlan::default(); MicrobitFrame::ROWS])
     }
+
+    /// Stores an image in the frame, rotated or flipped as specified by
+    /// `orientation`.
+    ///
+    /// `frame.set_oriented(image, Transform::Identity)` is equivalent to
+    /// `frame.set(image)`.
+    ///
+    /// Use this with [`MicrobitDisplay::orientation()`] to prepare a frame
+    /// for [`MicrobitDisplay::set_frame()`].
+    ///
+    /// [`MicrobitDisplay::orientation()`]: crate::display::MicrobitDisplay::orientation
+    /// [`MicrobitDisplay::set_frame()`]: crate::display::MicrobitDisplay::set_frame
+    pub fn set_oriented<R: Render + ?Sized>(&mut self, image: &R, orientation: Transform) {
+        self.set(&Transformed::new(image, orientation));
+    }
 }
 
 impl Default for MicrobitFrame {
@@ -1,16 +1,31 @@
 //! Wrapper for a Display, its GPIO pins, and a timer.
 
 use nrf51_hal::hi_res_timer::As16BitTimer;
-use tiny_led_matrix::{Display, Event as DisplayEvent};
+use tiny_led_matrix::{Display, Event as DisplayEvent, Render};
 use crate::display::display_port::DisplayPort;
 use crate::display::matrix::MicrobitFrame;
 use crate::display::timer::MicrobitDisplayTimer;
+use crate::graphics::transform::Transform;
 
 /// The micro:bit's display, and one timer to drive it.
+///
+/// The display has an orientation, which is applied to images passed to
+/// [`set_image()`]. See [`set_orientation()`] for details.
+///
+/// Only `set_image()` honours the orientation. [`set_frame()`] takes a
+/// [`MicrobitFrame`], in which the image has already been converted to
+/// per-row LED timings that can't be rotated; prepare such frames using
+/// [`MicrobitFrame::set_oriented()`] if they should be oriented.
+///
+/// [`set_image()`]: MicrobitDisplay::set_image
+/// [`set_frame()`]: MicrobitDisplay::set_frame
+/// [`set_orientation()`]: MicrobitDisplay::set_orientation
+/// [`MicrobitFrame::set_oriented()`]: crate::display::MicrobitFrame::set_oriented
 pub struct MicrobitDisplay<T: As16BitTimer> {
     timer: MicrobitDisplayTimer<T>,
     port: DisplayPort,
     display: Display<MicrobitFrame>,
+    orientation: Transform,
 }
 
 impl<T: As16BitTimer> MicrobitDisplay<T> {
@@ -23,7 +38,8 @@ impl<T: As16BitTimer> MicrobitDisplay<T> {
     ///
     /// Initialises the micro:bit hardware to use the display driver.
     ///
-    /// The display is initially clear.
+    /// The display is initially clear, and its orientation is
+    /// `Transform::Identity`.
     ///
     /// # Example
     ///
@@ -41,7 +57,7 @@ impl<T: As16BitTimer> MicrobitDisplay<T> {
         tiny_led_matrix::initialise_control(&mut port);
         tiny_led_matrix::initialise_timer(&mut timer);
         let display = Display::new();
-        MicrobitDisplay {timer, port, display}
+        MicrobitDisplay {timer, port, display, orientation: Transform::Identity}
     }
 
     /// Gives the underlying devices back.
@@ -110,5 +126,49 @@ impl<T: As16BitTimer> MicrobitDisplay<T> {
         self.display.set_frame(frame);
     }
 
+    /// Returns the display's orientation.
+    pub fn orientation(&self) -> Transform {
+        self.orientation
+    }
+
+    /// Sets the display's orientation.
+    ///
+    /// The orientation is a [`Transform`] which is applied to each image
+    /// passed to [`set_image()`]. For example, if the micro:bit is mounted
+    /// upside down, use `Transform::Rotate180`.
+    ///
+    /// Changing the orientation doesn't affect what's currently displayed.
+    ///
+    /// [`set_frame()`][set_frame] doesn't use the orientation directly; if
+    /// you prepare your own frames, use
+    /// [`MicrobitFrame::set_oriented()`] with the value of [`orientation()`].
+    ///
+    /// [`set_image()`]: MicrobitDisplay::set_image
+    /// [set_frame]: MicrobitDisplay::set_frame
+    /// [`orientation()`]: MicrobitDisplay::orientation
+    /// [`MicrobitFrame::set_oriented()`]: crate::display::MicrobitFrame::set_oriented
+    pub fn set_orientation(&mut self, orientation: Transform) {
+        self.orientation = orientation;
+    }
+
+    /// Accepts a new image to be displayed, applying the display's
+    /// orientation.
+    ///
+    /// This is equivalent to storing the image in a [`MicrobitFrame`] using
+    /// [`MicrobitFrame::set_oriented()`] and passing that to
+    /// [`set_frame()`][set_frame].
+    ///
+    /// The same restrictions apply as for `set_frame()`. Note that this
+    /// method does the work of converting the image to a frame, so it takes
+    /// longer than `set_frame()`.
+    ///
+    /// [set_frame]: MicrobitDisplay::set_frame
+    /// [`MicrobitFrame::set_oriented()`]: crate::display::MicrobitFrame::set_oriented
+    pub fn set_image<R: Render + ?Sized>(&mut self, image: &R) {
+        let mut frame = MicrobitFrame::default();
+        frame.set_oriented(image, self.orientation);
+        self.display.set_frame(&frame);
+    }
+
 }
 
@@ -0,0 +1,475 @@
+//! A host-side simulation of the micro:bit's LED display.
+//!
+//! This module is available when the `sim` cargo feature is enabled (and
+//! always in this crate's own unit tests).
+//!
+//! It provides [`SimDisplayPort`] and [`SimDisplayTimer`], which implement
+//! [`DisplayControl`] and [`DisplayTimer`] without touching any hardware, and
+//! a [`SimulatedDisplay`] which uses them to drive the same
+//! [`tiny_led_matrix::Display`] that [`MicrobitDisplay`] uses.
+//!
+//! The simulation keeps track of simulated time (in 16 µs timer ticks) and
+//! records how long each LED is lit. It converts the GPIO pin state to LED
+//! positions using the same layout table as [`MicrobitFrame`].
+//!
+//! The perceived brightness of each LED is reconstructed from its on-time
+//! over a complete refresh of the display (three 6ms primary cycles).
+//!
+//! # Example
+//!
+//! ```
+//! use rmicrobit::prelude::*;
+//! use rmicrobit::display::MicrobitFrame;
+//! use rmicrobit::display::sim::SimulatedDisplay;
+//! use rmicrobit::graphics::image::GreyscaleImage;
+//! const IMAGE: GreyscaleImage = GreyscaleImage::new(&[
+//!     [0, 1, 2, 3, 4],
+//!     [5, 6, 7, 8, 9],
+//!     [0, 0, 0, 0, 0],
+//!     [9, 0, 9, 0, 9],
+//!     [0, 5, 0, 5, 0],
+//! ]);
+//! let mut display = SimulatedDisplay::new();
+//! let mut frame = MicrobitFrame::default();
+//! frame.set(&IMAGE);
+//! display.set_frame(&frame);
+//! display.run_refresh();
+//! assert_eq!(display.perceived_brightness_at(3, 1), 8);
+//! ```
+//!
+//! [`DisplayControl`]: tiny_led_matrix::DisplayControl
+//! [`DisplayTimer`]: tiny_led_matrix::DisplayTimer
+//! [`MicrobitDisplay`]: crate::display::MicrobitDisplay
+//! [`MicrobitFrame`]: crate::display::MicrobitFrame
+
+use tiny_led_matrix::{
+    Display,
+    DisplayControl,
+    DisplayTimer,
+    Event as DisplayEvent,
+    Frame,
+    Matrix,
+    Render,
+    MAX_BRIGHTNESS,
+};
+use crate::display::display_port::pin_constants::*;
+use crate::display::matrix::{MicrobitFrame, MicrobitMatrix};
+use crate::graphics::image::GreyscaleImage;
+use crate::graphics::transform::Transform;
+
+#[cfg(test)]
+#[path = "../../unit_tests/display/sim_tests.rs"]
+mod tests;
+
+
+/// A simulated GPIO port for the display's row and column pins.
+///
+/// `SimDisplayPort` implements [`DisplayControl`] in the same way as
+/// [`DisplayPort`], but writes to an in-memory copy of the GPIO output
+/// register.
+///
+/// It can also record how long each LED has been lit; see
+/// [`advance()`][SimDisplayPort::advance].
+///
+/// [`DisplayControl`]: tiny_led_matrix::DisplayControl
+/// [`DisplayPort`]: crate::display::DisplayPort
+#[derive(Debug)]
+pub struct SimDisplayPort {
+    pins: u32,
+    on_ticks: [[u32; 5]; 5],
+}
+
+impl SimDisplayPort {
+
+    /// Returns a new `SimDisplayPort` with all the pins low.
+    pub const fn new() -> SimDisplayPort {
+        SimDisplayPort {
+            pins: 0,
+            on_ticks: [[0; 5]; 5],
+        }
+    }
+
+    /// Returns the simulated GPIO output register.
+    ///
+    /// Only the row and column pins are ever set.
+    pub fn pins(&self) -> u32 {
+        self.pins
+    }
+
+    /// Sets the specified pins high, leaving the others unchanged.
+    ///
+    /// Bits in `pins` not representing row or column pins are ignored.
+    pub fn set(&mut self, pins: u32) {
+        self.pins |= pins & (ROW_PINS_MASK | COL_PINS_MASK);
+    }
+
+    /// Sets the specified pins low, leaving the others unchanged.
+    ///
+    /// Bits in `pins` not representing row or column pins are ignored.
+    pub fn clear(&mut self, pins: u32) {
+        self.pins &= !(pins & (ROW_PINS_MASK | COL_PINS_MASK));
+    }
+
+    /// Says whether the LED at (x, y) is currently lit.
+    ///
+    /// An LED is lit when its row pin is high and its column pin is low.
+    pub fn is_lit(&self, x: usize, y: usize) -> bool {
+        for row in 0..MATRIX_ROWS {
+            for col in 0..MATRIX_COLS {
+                if MicrobitMatrix::image_coordinates(col, row) == Some((x, y)) {
+                    return self.pin_pair_is_lit(col, row);
+                }
+            }
+        }
+        false
+    }
+
+    fn pin_pair_is_lit(&self, col: usize, row: usize) -> bool {
+        self.pins & 1<<row_pin_number(row) != 0 &&
+            self.pins & 1<<col_pin_number(col) == 0
+    }
+
+    /// Records the passing of `ticks` timer ticks.
+    ///
+    /// Adds `ticks` to the on-time of each LED which is currently lit.
+    pub fn advance(&mut self, ticks: u32) {
+        for row in 0..MATRIX_ROWS {
+            for col in 0..MATRIX_COLS {
+                if !self.pin_pair_is_lit(col, row) {continue}
+                if let Some((x, y)) = MicrobitMatrix::image_coordinates(col, row) {
+                    self.on_ticks[y][x] += ticks;
+                }
+            }
+        }
+    }
+
+    /// Returns the number of ticks for which the LED at (x, y) has been lit
+    /// since the last call to [`clear_on_ticks()`].
+    ///
+    /// [`clear_on_ticks()`]: SimDisplayPort::clear_on_ticks
+    pub fn on_ticks(&self, x: usize, y: usize) -> u32 {
+        self.on_ticks[y][x]
+    }
+
+    /// Resets the recorded on-time of every LED to zero.
+    pub fn clear_on_ticks(&mut self) {
+        self.on_ticks = [[0; 5]; 5];
+    }
+
+}
+
+impl Default for SimDisplayPort {
+
+    fn default() -> SimDisplayPort {
+        SimDisplayPort::new()
+    }
+
+}
+
+impl DisplayControl for SimDisplayPort {
+
+    fn initialise_for_display(&mut self) {
+    }
+
+    fn display_row_leds(&mut self, row: usize, cols: u32) {
+        let rows_to_set = 1<<(FIRST_ROW_PIN+row);
+        let rows_to_clear = ROW_PINS_MASK ^ rows_to_set;
+        let cols_to_clear = cols << FIRST_COL_PIN;
+        let cols_to_set = COL_PINS_MASK ^ cols_to_clear;
+
+        self.set(rows_to_set | cols_to_set);
+        self.clear(rows_to_clear | cols_to_clear);
+    }
+
+    fn light_current_row_leds(&mut self, cols: u32) {
+        self.clear(cols << FIRST_COL_PIN)
+    }
+
+}
+
+
+/// A simulated 16-bit TIMER, as programmed by the display driver.
+///
+/// `SimDisplayTimer` implements [`DisplayTimer`]. It doesn't keep time
+/// itself: whatever is driving the simulation reports compare events using
+/// [`fire_primary()`] and [`fire_secondary()`].
+///
+/// [`DisplayTimer`]: tiny_led_matrix::DisplayTimer
+/// [`fire_primary()`]: SimDisplayTimer::fire_primary
+/// [`fire_secondary()`]: SimDisplayTimer::fire_secondary
+#[derive(Debug, Default)]
+pub struct SimDisplayTimer {
+    cycle_ticks: u16,
+    secondary_ticks: u16,
+    secondary_enabled: bool,
+    primary_event: bool,
+    secondary_event: bool,
+}
+
+impl SimDisplayTimer {
+
+    /// Returns a new `SimDisplayTimer`, not yet started.
+    pub const fn new() -> SimDisplayTimer {
+        SimDisplayTimer {
+            cycle_ticks: 0,
+            secondary_ticks: 0,
+            secondary_enabled: false,
+            primary_event: false,
+            secondary_event: false,
+        }
+    }
+
+    /// Returns the length of the primary cycle, in ticks.
+    ///
+    /// Returns 0 if the cycle hasn't been initialised.
+    pub fn cycle_ticks(&self) -> u16 {
+        self.cycle_ticks
+    }
+
+    /// Returns the tick count at which the secondary alarm is due, if it's
+    /// enabled.
+    pub fn secondary_alarm(&self) -> Option<u16> {
+        if self.secondary_enabled {Some(self.secondary_ticks)} else {None}
+    }
+
+    /// Records that the primary cycle has completed.
+    pub fn fire_primary(&mut self) {
+        self.primary_event = true;
+    }
+
+    /// Records that the counter has reached the secondary alarm's value.
+    pub fn fire_secondary(&mut self) {
+        self.secondary_event = true;
+    }
+
+}
+
+impl DisplayTimer for SimDisplayTimer {
+
+    fn initialise_cycle(&mut self, ticks: u16) {
+        self.cycle_ticks = ticks;
+    }
+
+    fn enable_secondary(&mut self) {
+        self.secondary_enabled = true;
+    }
+
+    fn disable_secondary(&mut self) {
+        self.secondary_enabled = false;
+    }
+
+    fn program_secondary(&mut self, ticks: u16) {
+        self.secondary_ticks = ticks;
+    }
+
+    fn check_primary(&mut self) -> bool {
+        let fired = self.primary_event;
+        self.primary_event = false;
+        fired
+    }
+
+    fn check_secondary(&mut self) -> bool {
+        let fired = self.secondary_event;
+        self.secondary_event = false;
+        fired
+    }
+
+}
+
+
+/// A simulated micro:bit display.
+///
+/// This has the same `set_frame()`, `set_image()`, `handle_event()` and
+/// orientation interface as [`MicrobitDisplay`], using a [`SimDisplayPort`] and a
+/// [`SimDisplayTimer`] in place of the hardware.
+///
+/// Use [`run_cycle()`] or [`run_refresh()`] to advance simulated time,
+/// calling `handle_event()` whenever the timer would have caused an
+/// interrupt.
+///
+/// [`MicrobitDisplay`]: crate::display::MicrobitDisplay
+/// [`run_cycle()`]: SimulatedDisplay::run_cycle
+/// [`run_refresh()`]: SimulatedDisplay::run_refresh
+pub struct SimulatedDisplay {
+    timer: SimDisplayTimer,
+    port: SimDisplayPort,
+    display: Display<MicrobitFrame>,
+    orientation: Transform,
+    // ticks each LED is lit for at each brightness level, over a refresh
+    level_ticks: [u32; MAX_BRIGHTNESS as usize + 1],
+}
+
+impl SimulatedDisplay {
+
+    /// Returns a new `SimulatedDisplay`.
+    ///
+    /// The display is initially clear, and no time has been recorded.
+    pub fn new() -> SimulatedDisplay {
+        let mut port = SimDisplayPort::new();
+        let mut timer = SimDisplayTimer::new();
+        tiny_led_matrix::initialise_control(&mut port);
+        tiny_led_matrix::initialise_timer(&mut timer);
+        let mut sim = SimulatedDisplay {
+            timer,
+            port,
+            display: Display::new(),
+            orientation: Transform::Identity,
+            level_ticks: [0; MAX_BRIGHTNESS as usize + 1],
+        };
+        sim.calibrate();
+        sim
+    }
+
+    // Finds out how long the display driver lights an LED for at each
+    // brightness level, by displaying an image which uses all of them.
+    fn calibrate(&mut self) {
+        struct AllLevels();
+        impl Render for AllLevels {
+            fn brightness_at(&self, x: usize, y: usize) -> u8 {
+                ((y*5 + x) % (MAX_BRIGHTNESS as usize + 1)) as u8
+            }
+        }
+        let mut frame = MicrobitFrame::default();
+        frame.set(&AllLevels());
+        self.set_frame(&frame);
+        self.run_refresh();
+        for level in 0..=MAX_BRIGHTNESS as usize {
+            self.level_ticks[level] = self.port.on_ticks(level % 5, level / 5);
+        }
+        self.set_frame(&MicrobitFrame::default());
+        self.run_refresh();
+        self.port.clear_on_ticks();
+    }
+
+    /// Accepts a new image to be displayed.
+    ///
+    /// As for [`MicrobitDisplay::set_frame()`], the new image is used from
+    /// the start of the next primary cycle.
+    ///
+    /// [`MicrobitDisplay::set_frame()`]: crate::display::MicrobitDisplay::set_frame
+    pub fn set_frame(&mut self, frame: &MicrobitFrame) {
+        self.display.set_frame(frame);
+    }
+
+    /// Returns the display's orientation.
+    pub fn orientation(&self) -> Transform {
+        self.orientation
+    }
+
+    /// Sets the display's orientation.
+    ///
+    /// See [`MicrobitDisplay::set_orientation()`].
+    ///
+    /// [`MicrobitDisplay::set_orientation()`]: crate::display::MicrobitDisplay::set_orientation
+    pub fn set_orientation(&mut self, orientation: Transform) {
+        self.orientation = orientation;
+    }
+
+    /// Accepts a new image to be displayed, applying the display's
+    /// orientation.
+    ///
+    /// See [`MicrobitDisplay::set_image()`].
+    ///
+    /// [`MicrobitDisplay::set_image()`]: crate::display::MicrobitDisplay::set_image
+    pub fn set_image<R: Render + ?Sized>(&mut self, image: &R) {
+        let mut frame = MicrobitFrame::default();
+        frame.set_oriented(image, self.orientation);
+        self.display.set_frame(&frame);
+    }
+
+    /// Updates the simulated LEDs and timer state, as for a timer interrupt.
+    ///
+    /// [`run_cycle()`] calls this automatically.
+    ///
+    /// [`run_cycle()`]: SimulatedDisplay::run_cycle
+    pub fn handle_event(&mut self) -> DisplayEvent {
+        self.display.handle_event(&mut self.timer, &mut self.port)
+    }
+
+    /// Simulates a single 6ms primary cycle.
+    ///
+    /// Fires the primary event at the start of the cycle, then fires the
+    /// secondary alarm each time the counter reaches it, recording LED
+    /// on-time as it goes.
+    pub fn run_cycle(&mut self) {
+        let cycle_ticks = self.timer.cycle_ticks();
+        let mut now = 0;
+        self.timer.fire_primary();
+        self.handle_event();
+        while let Some(alarm) = self.timer.secondary_alarm() {
+            if alarm <= now || alarm >= cycle_ticks {break}
+            self.port.advance((alarm - now) as u32);
+            now = alarm;
+            self.timer.fire_secondary();
+            self.handle_event();
+        }
+        self.port.advance((cycle_ticks - now) as u32);
+    }
+
+    /// Simulates a complete refresh of the display (three primary cycles,
+    /// one for each internal LED row).
+    pub fn run_refresh(&mut self) {
+        for _ in 0..MATRIX_ROWS {
+            self.run_cycle();
+        }
+    }
+
+    /// Returns the simulated display port.
+    pub fn port(&self) -> &SimDisplayPort {
+        &self.port
+    }
+
+    /// Returns the simulated timer.
+    pub fn timer(&self) -> &SimDisplayTimer {
+        &self.timer
+    }
+
+    /// Resets the recorded on-time of every LED to zero.
+    pub fn clear_on_ticks(&mut self) {
+        self.port.clear_on_ticks();
+    }
+
+    /// Returns the perceived brightness of the LED at (x, y).
+    ///
+    /// This is the brightness level whose on-time over a single refresh is
+    /// closest to the LED's recorded on-time.
+    ///
+    /// Call [`clear_on_ticks()`] and then [`run_refresh()`] first, so that
+    /// the recorded on-time covers exactly one refresh.
+    ///
+    /// [`clear_on_ticks()`]: SimulatedDisplay::clear_on_ticks
+    /// [`run_refresh()`]: SimulatedDisplay::run_refresh
+    pub fn perceived_brightness_at(&self, x: usize, y: usize) -> u8 {
+        let ticks = self.port.on_ticks(x, y);
+        let distance = |level: usize| {
+            (self.level_ticks[level] as i64 - ticks as i64).abs()
+        };
+        (0..=MAX_BRIGHTNESS as usize)
+            .min_by_key(|&level| distance(level))
+            .unwrap() as u8
+    }
+
+    /// Returns the perceived image, as a `GreyscaleImage`.
+    ///
+    /// See [`perceived_brightness_at()`] for details.
+    ///
+    /// [`perceived_brightness_at()`]: SimulatedDisplay::perceived_brightness_at
+    pub fn perceived_image(&self) -> GreyscaleImage {
+        let mut data = [[0; 5]; 5];
+        for (y, row) in data.iter_mut().enumerate() {
+            for (x, value) in row.iter_mut().enumerate() {
+                *value = self.perceived_brightness_at(x, y);
+            }
+        }
+        GreyscaleImage::new(&data)
+    }
+
+}
+
+impl Default for SimulatedDisplay {
+
+    fn default() -> SimulatedDisplay {
+        SimulatedDisplay::new()
+    }
+
+}
@@ -4,7 +4,8 @@
 //!
 //! This module provides:
 //! - simple 5×5 greyscale and black-and-white image types;
-//! - a copy of the 'pendolino' font from the [micro:bit runtime][dal];
+//! - a copy of the 'pendolino' font from the [micro:bit runtime][dal], and a
+//!   compact 3×5 font;
 //! - support for scrolling text.
 //!
 //! # The `Render` trait
@@ -22,35 +23,135 @@
 //! - [`GreyscaleImage`], allowing all 10 levels (using one byte for each LED)
 //! - [`BitImage`], allowing only 'on' and 'off' (using five bytes)
 //!
+//! The [`images`] module provides the standard images from MicroPython's
+//! `Image` class (hearts, arrows, clock hands, faces, and so on).
+//!
+//! The [`greyscale_image!`] and [`bit_image!`] macros make image constants
+//! from MicroPython-style strings or ascii art, checked at compile time (see
+//! [`literal`]).
+//!
+//! The `tools/imagec` program in the `rmicrobit` repository converts BDF
+//! fonts, PBM/PGM images and ascii-art text files into Rust source for
+//! constants of these types.
+//!
+//! # Larger images
+//!
+//! The [`canvas`] module provides a [`Canvas`] type for greyscale images
+//! larger than 5×5, which displays a movable 5×5 viewport. Panning the
+//! viewport uses the same [`Animate`] interface as scrolling.
+//!
+//! # Drawing
+//!
+//! The [`draw`] module provides a [`Draw`] trait with clipped drawing
+//! operations (points, lines, rectangles, circles and flood fill), implemented
+//! for [`GreyscaleImage`] and [`Canvas`].
+//!
+//! # Charts
+//!
+//! The [`charts`] module provides `Render` types for showing values: a
+//! [`BarGraph`], a 25-LED [`Gauge`], and a [`Sparkline`] of recent samples.
+//!
+//! # Transforms
+//!
+//! The [`transform`] module supports rotating, flipping and shifting images,
+//! either by making a transformed copy of a [`GreyscaleImage`] or
+//! [`BitImage`], or by wrapping any `Render` in an adapter.
+//!
+//! # Combining images
+//!
+//! The [`compose`] module provides `Render` adapters which combine two
+//! images (for example, displaying one on top of another).
+//!
 //! # Fonts
 //!
-//! The [`font`] module provides 5×5 representations of the ascii printable
-//! characters as [`BitImage`]s.
+//! The [`font`] module defines a [`Font`] trait, which maps characters to
+//! 5×5 [`BitImage`]s, and provides two fonts:
+//!
+//! - [`Pendolino`], covering the ascii printable characters (taken from the
+//!   "pendolino" font supplied with the [micro:bit runtime][dal]) and the
+//!   Latin-1 accented letters and symbols;
+//! - [`Compact`], a font of 3×5 glyphs for digits, letters and common
+//!   punctuation.
+//!
+//! The text scrollers accept any `Font`.
 //!
-//! These are taken from the "pendolino" font supplied with the
-//! [micro:bit runtime][dal].
+//! # Text representations
+//!
+//! The [`ascii_art`] module can format any `Render` as a grid of brightness
+//! glyphs, and parse the same format back into a [`GreyscaleImage`]. This is
+//! useful for debugging output and for writing expected frames in tests.
 //!
 //! # Scrolling images and text
 //!
-//! The [`scrolling`] module supports horizontal scrolling for a sequence of
-//! images via a [`ScrollingImages`] type which implements `Render` and an
-//! [`Animate`] interface.
+//! The [`scrolling`] module supports scrolling a sequence of images (left,
+//! right, up or down) via a [`ScrollingImages`] type which implements
+//! `Render` and an [`Animate`] interface.
 //!
 //! The [`scrolling_text`] module supports scrolling messages, providing
 //! [`ScrollingStaticText`] and [`ScrollingBufferedText`] types.
 //!
+//! The [`scrolling_items`] module provides a [`ScrollingItems`] type which
+//! scrolls a mixture of text, images and blank space.
+//!
+//! # Frame-by-frame animation
+//!
+//! The [`sequence`] module provides a [`Sequence`] type which displays a
+//! series of images, each for a given number of ticks, once or in a loop. It
+//! uses the same [`Animate`] interface as scrolling.
+//!
+//! # Transitions
+//!
+//! The [`transitions`] module provides a [`Transition`] type which animates
+//! a change from one image to another (fading, wiping, dissolving or zooming),
+//! using the full range of brightness levels.
+//!
+//! # Animation speed
+//!
+//! The [`pacing`] module provides a [`Paced`] adapter, which runs any
+//! `Animate` implementation at a fraction (or multiple) of the rate at which
+//! it's ticked.
+//!
 //! [dal]: https://lancaster-university.github.io/microbit-docs/
 //! [greyscale]: crate::display
 //! [`Animate`]: scrolling::Animate
+//! [`bit_image!`]: crate::bit_image
+//! [`BarGraph`]: charts::BarGraph
 //! [`BitImage`]: image::BitImage
+//! [`Canvas`]: canvas::Canvas
+//! [`Compact`]: font::Compact
+//! [`Draw`]: draw::Draw
+//! [`Font`]: font::Font
+//! [`Gauge`]: charts::Gauge
 //! [`GreyscaleImage`]: image::GreyscaleImage
+//! [`greyscale_image!`]: crate::greyscale_image
+//! [`Paced`]: pacing::Paced
+//! [`Pendolino`]: font::Pendolino
 //! [`display::Render`]: crate::display::Render
 //! [`Scrollable`]: scrolling::Scrollable
+//! [`Sequence`]: sequence::Sequence
+//! [`Sparkline`]: charts::Sparkline
+//! [`Transition`]: transitions::Transition
 //! [`ScrollingImages`]: scrolling::ScrollingImages
+//! [`ScrollingItems`]: scrolling_items::ScrollingItems
 //! [`ScrollingBufferedText`]: scrolling_text::ScrollingBufferedText
 //! [`ScrollingStaticText`]: scrolling_text::ScrollingStaticText
 
+pub mod ascii_art;
+pub mod canvas;
+pub mod charts;
+pub mod compose;
+pub mod draw;
 pub mod font;
 pub mod image;
+pub mod images;
+pub mod literal;
+pub mod pacing;
 pub mod scrolling;
+pub mod scrolling_items;
 pub mod scrolling_text;
+pub mod sequence;
+pub mod transform;
+pub mod transitions;
+
+#[cfg(test)]
+mod test_support;
@@ -0,0 +1,155 @@
+//! Text representations of 5×5 images.
+//!
+//! [`AsciiArt`] formats anything implementing [`Render`] as a grid of
+//! brightness glyphs, one line per row:
+//!
+//! ```text
+//! .9.9.
+//! 95959
+//! 95559
+//! .959.
+//! ..9..
+//! ```
+//!
+//! Brightness 0 is shown as `.` and the other levels as the digits `1` to
+//! `9`.
+//!
+//! [`parse()`] reads the same format back into a [`GreyscaleImage`], so it
+//! can be used to write expected frames in tests. It also accepts `0` for
+//! brightness 0 and `#` for full brightness, and ignores leading and trailing
+//! whitespace on each line and blank lines.
+//!
+//! # Example
+//!
+//! ```
+//! use rmicrobit::graphics::ascii_art::{parse, AsciiArt};
+//! use rmicrobit::graphics::image::BitImage;
+//! const CROSS: BitImage = BitImage::new(&[
+//!     [1, 0, 0, 0, 1],
+//!     [0, 1, 0, 1, 0],
+//!     [0, 0, 1, 0, 0],
+//!     [0, 1, 0, 1, 0],
+//!     [1, 0, 0, 0, 1],
+//! ]);
+//! let expected = parse("
+//!     #...#
+//!     .#.#.
+//!     ..#..
+//!     .#.#.
+//!     #...#
+//! ").unwrap();
+//! assert_eq!(AsciiArt(&CROSS).to_string(), AsciiArt(&expected).to_string());
+//! ```
+//!
+//! [`GreyscaleImage`]: crate::graphics::image::GreyscaleImage
+//! [`Render`]: crate::display::Render
+
+use core::fmt;
+use core::str::FromStr;
+use tiny_led_matrix::{Render, MAX_BRIGHTNESS};
+use crate::graphics::image::GreyscaleImage;
+
+#[cfg(test)]
+#[path = "../../unit_tests/graphics/ascii_art_tests.rs"]
+mod tests;
+
+/// The glyphs used by [`AsciiArt`], indexed by brightness.
+pub const GLYPHS: [char; 10] = ['.', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+
+/// Adapter to format a [`Render`] as a 5×5 grid of brightness glyphs.
+///
+/// The rows are separated by newlines (there's no newline after the last
+/// row).
+///
+/// Brightness values above [`MAX_BRIGHTNESS`] are shown as `9`.
+///
+/// [`Render`]: crate::display::Render
+/// [`MAX_BRIGHTNESS`]: crate::display::MAX_BRIGHTNESS
+pub struct AsciiArt<'a, T: Render + ?Sized>(pub &'a T);
+
+impl<T: Render + ?Sized> fmt::Display for AsciiArt<'_, T> {
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..5 {
+            if y != 0 {
+                f.write_str("\n")?;
+            }
+            for x in 0..5 {
+                let brightness = self.0.brightness_at(x, y).min(MAX_BRIGHTNESS);
+                write!(f, "{}", GLYPHS[brightness as usize])?;
+            }
+        }
+        Ok(())
+    }
+
+}
+
+impl<T: Render + ?Sized> fmt::Debug for AsciiArt<'_, T> {
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "\n{}\n", self)
+    }
+
+}
+
+
+/// An error from [`parse()`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ParseError {
+    /// A character which isn't a brightness glyph.
+    BadGlyph(char),
+    /// A row (numbered from 0) which doesn't have exactly 5 glyphs.
+    BadRowLength(usize),
+    /// The text doesn't have exactly 5 rows.
+    BadRowCount,
+}
+
+impl fmt::Display for ParseError {
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::BadGlyph(c) => write!(f, "unexpected character {:?}", c),
+            ParseError::BadRowLength(row) => write!(f, "row {} is not 5 pixels wide", row),
+            ParseError::BadRowCount => write!(f, "image is not 5 rows high"),
+        }
+    }
+
+}
+
+fn glyph_brightness(c: char) -> Result<u8, ParseError> {
+    match c {
+        '.' => Ok(0),
+        '#' => Ok(MAX_BRIGHTNESS),
+        '0'..='9' => Ok(c as u8 - b'0'),
+        _ => Err(ParseError::BadGlyph(c)),
+    }
+}
+
+/// Reads a 5×5 grid of brightness glyphs into a `GreyscaleImage`.
+///
+/// See the [module-level documentation](self) for the format.
+pub fn parse(text: &str) -> Result<GreyscaleImage, ParseError> {
+    let mut data = [[0; 5]; 5];
+    let mut rows = 0;
+    let lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+    for (y, line) in lines.enumerate() {
+        if y == 5 {return Err(ParseError::BadRowCount)}
+        if line.chars().count() != 5 {return Err(ParseError::BadRowLength(y))}
+        for (x, c) in line.chars().enumerate() {
+            data[y][x] = glyph_brightness(c)?;
+        }
+        rows += 1;
+    }
+    if rows != 5 {return Err(ParseError::BadRowCount)}
+    Ok(GreyscaleImage::new(&data))
+}
+
+impl FromStr for GreyscaleImage {
+
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<GreyscaleImage, ParseError> {
+        parse(s)
+    }
+
+}
@@ -0,0 +1,182 @@
+//! Greyscale images larger than the display.
+//!
+//! A [`Canvas`] stores a `W`×`H` greyscale image, and displays the 5×5
+//! region of it selected by its *viewport*.
+//!
+//! The viewport can be moved directly using
+//! [`set_viewport()`][Canvas::set_viewport], or gradually using
+//! [`pan_to()`][Canvas::pan_to] and the [`Animate`] interface.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use rmicrobit::prelude::*;
+//! use rmicrobit::graphics::canvas::Canvas;
+//! let mut map: Canvas<16, 8> = Canvas::new();
+//! map.draw(0, 0, &HOUSE);
+//! map.draw(11, 3, &TREE);
+//! map.pan_to(11, 3);
+//! while !map.is_finished() {
+//!     // every 50ms or so
+//!     map.tick();
+//!     frame.set(&map);
+//!     display.set_frame(frame);
+//! }
+//! ```
+//!
+//! [`Animate`]: crate::graphics::scrolling::Animate
+
+use tiny_led_matrix::Render;
+use crate::graphics::scrolling::Animate;
+
+#[cfg(test)]
+#[path = "../../unit_tests/graphics/canvas_tests.rs"]
+mod tests;
+
+/// A `W`×`H` greyscale image with a movable 5×5 viewport.
+///
+/// Uses `W`×`H` bytes of storage for the image.
+///
+/// `Canvas` implements [`Render`], displaying the pixels in the viewport.
+/// The viewport may extend beyond the edges of the canvas; pixels outside
+/// the canvas are displayed as 0.
+///
+/// `Canvas` implements [`Animate`]: each tick moves the viewport one pixel
+/// (horizontally, vertically, or both) towards the position requested by
+/// [`pan_to()`][Canvas::pan_to].
+///
+/// [`Animate`]: crate::graphics::scrolling::Animate
+/// [`Render`]: crate::display::Render
+#[derive(Copy, Clone, Debug)]
+pub struct Canvas<const W: usize, const H: usize> {
+    data: [[u8; W]; H],
+    // current viewport position (top-left)
+    viewport: (isize, isize),
+    // viewport position when the current pan started
+    pan_start: (isize, isize),
+    // viewport position the current pan is heading for
+    pan_target: (isize, isize),
+}
+
+impl<const W: usize, const H: usize> Canvas<W, H> {
+
+    /// Returns a new blank canvas, with the viewport at (0, 0).
+    pub const fn new() -> Canvas<W, H> {
+        Canvas {
+            data: [[0; W]; H],
+            viewport: (0, 0),
+            pan_start: (0, 0),
+            pan_target: (0, 0),
+        }
+    }
+
+    /// The width of the canvas, in pixels.
+    pub const fn width(&self) -> usize {
+        W
+    }
+
+    /// The height of the canvas, in pixels.
+    pub const fn height(&self) -> usize {
+        H
+    }
+
+    /// Returns the brightness of the pixel at (x, y).
+    ///
+    /// Returns 0 if (x, y) is outside the canvas.
+    pub fn pixel(&self, x: isize, y: isize) -> u8 {
+        if x < 0 || y < 0 || x as usize >= W || y as usize >= H {return 0}
+        self.data[y as usize][x as usize]
+    }
+
+    /// Sets the brightness of the pixel at (x, y).
+    ///
+    /// Does nothing if (x, y) is outside the canvas.
+    pub fn set_pixel(&mut self, x: isize, y: isize, brightness: u8) {
+        if x < 0 || y < 0 || x as usize >= W || y as usize >= H {return}
+        self.data[y as usize][x as usize] = brightness;
+    }
+
+    /// Sets all pixels to `brightness`.
+    pub fn fill(&mut self, brightness: u8) {
+        self.data = [[brightness; W]; H];
+    }
+
+    /// Sets all pixels to 0.
+    pub fn clear(&mut self) {
+        self.fill(0);
+    }
+
+    /// Copies a 5×5 image onto the canvas, with its top-left corner at
+    /// (x, y).
+    ///
+    /// Parts of the image which fall outside the canvas are ignored.
+    pub fn draw<R: Render + ?Sized>(&mut self, x: isize, y: isize, image: &R) {
+        for iy in 0..5 {
+            for ix in 0..5 {
+                let brightness = image.brightness_at(ix, iy);
+                self.set_pixel(x + ix as isize, y + iy as isize, brightness);
+            }
+        }
+    }
+
+    /// Returns the position of the viewport's top-left corner.
+    pub fn viewport(&self) -> (isize, isize) {
+        self.viewport
+    }
+
+    /// Moves the viewport so that its top-left corner is at (x, y).
+    ///
+    /// This cancels any pan in progress.
+    pub fn set_viewport(&mut self, x: isize, y: isize) {
+        self.viewport = (x, y);
+        self.pan_start = (x, y);
+        self.pan_target = (x, y);
+    }
+
+    /// Starts moving the viewport towards (x, y), one pixel per tick.
+    ///
+    /// The pan starts from the viewport's current position; `reset()` moves
+    /// the viewport back there.
+    pub fn pan_to(&mut self, x: isize, y: isize) {
+        self.pan_start = self.viewport;
+        self.pan_target = (x, y);
+    }
+
+}
+
+impl<const W: usize, const H: usize> Default for Canvas<W, H> {
+
+    fn default() -> Canvas<W, H> {
+        Canvas::new()
+    }
+
+}
+
+impl<const W: usize, const H: usize> Render for Canvas<W, H> {
+
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        self.pixel(self.viewport.0 + x as isize, self.viewport.1 + y as isize)
+    }
+
+}
+
+impl<const W: usize, const H: usize> Animate for Canvas<W, H> {
+
+    fn is_finished(&self) -> bool {
+        self.viewport == self.pan_target
+    }
+
+    fn reset(&mut self) {
+        self.viewport = self.pan_start;
+    }
+
+    fn tick(&mut self) {
+        let (x, y) = self.viewport;
+        let (target_x, target_y) = self.pan_target;
+        self.viewport = (
+            x + (target_x - x).signum(),
+            y + (target_y - y).signum(),
+        );
+    }
+
+}
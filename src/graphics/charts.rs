@@ -0,0 +1,285 @@
+//! Displaying values as bar graphs, gauges and sparklines.
+//!
+//! Each type in this module implements [`Render`]:
+//!
+//! Type            | Display |
+//! --------------- | ------- |
+//! [`BarGraph`]    | a bar the width of the display, whose length shows a value against a maximum |
+//! [`Gauge`]       | the 25 LEDs filled in reading order, in proportion to a value against a maximum |
+//! [`Sparkline`]   | the most recent samples as columns, scaled to the range of the samples kept |
+//!
+//! By default each LED is either off or at full brightness, and values are
+//! rounded to the nearest LED. In *smooth* mode, the LED at the end of the
+//! bar is lit at an intermediate brightness showing how much of it is
+//! filled, giving sub-LED resolution.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use rmicrobit::graphics::charts::BarGraph;
+//! use rmicrobit::graphics::scrolling::Direction;
+//! let mut graph = BarGraph::new(0, 1023, Direction::Up);
+//! graph.set_smooth(true);
+//! loop {
+//!     graph.set_value(read_light_level());
+//!     frame.set(&graph);
+//!     display.set_frame(frame);
+//! }
+//! ```
+//!
+//! [`Render`]: crate::display::Render
+
+use tiny_led_matrix::{Render, MAX_BRIGHTNESS};
+use crate::graphics::scrolling::Direction;
+
+#[cfg(test)]
+#[path = "../../unit_tests/graphics/charts_tests.rs"]
+mod tests;
+
+// Returns the brightness of the index'th of `count` LEDs in a bar filled to
+// the fraction `value`/`max`.
+fn fill_level(value: u64, max: u64, count: u64, index: u64, smooth: bool) -> u8 {
+    if max == 0 {return 0}
+    let value = value.min(max);
+    let full = u64::from(MAX_BRIGHTNESS);
+    if smooth {
+        let levels = value * count * full / max;
+        levels.saturating_sub(index * full).min(full) as u8
+    } else if 2 * value * count >= (2 * index + 1) * max {
+        full as u8
+    } else {
+        0
+    }
+}
+
+
+/// A bar graph showing a value against a maximum.
+///
+/// The bar fills the width of the display and grows in the specified
+/// direction: for example, with [`Direction::Up`] it grows from the bottom
+/// row, reaching the top row when the value is the maximum.
+///
+/// Values greater than the maximum are treated as the maximum. If the
+/// maximum is 0, nothing is displayed.
+///
+/// [`Direction::Up`]: crate::graphics::scrolling::Direction::Up
+#[derive(Copy, Clone, Debug)]
+pub struct BarGraph {
+    value: u32,
+    max: u32,
+    direction: Direction,
+    smooth: bool,
+}
+
+impl BarGraph {
+
+    /// Returns a `BarGraph` showing `value` against `max`, growing in
+    /// `direction`.
+    pub const fn new(value: u32, max: u32, direction: Direction) -> BarGraph {
+        BarGraph {value, max, direction, smooth: false}
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// Sets the value.
+    pub fn set_value(&mut self, value: u32) {
+        self.value = value;
+    }
+
+    /// Returns the maximum.
+    pub fn max(&self) -> u32 {
+        self.max
+    }
+
+    /// Sets the maximum.
+    pub fn set_max(&mut self, max: u32) {
+        self.max = max;
+    }
+
+    /// Sets whether the end of the bar uses intermediate brightnesses.
+    pub fn set_smooth(&mut self, smooth: bool) {
+        self.smooth = smooth;
+    }
+
+}
+
+impl Render for BarGraph {
+
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        // u counts from the edge the bar grows towards
+        let (u, _) = self.direction.strip_coordinates(x, y);
+        fill_level(u64::from(self.value), u64::from(self.max), 5, 4 - u as u64, self.smooth)
+    }
+
+}
+
+
+/// A gauge showing a value against a maximum using all 25 LEDs.
+///
+/// The LEDs are filled in reading order: left to right along the top row,
+/// then the next row, and so on.
+///
+/// Values greater than the maximum are treated as the maximum. If the
+/// maximum is 0, nothing is displayed.
+#[derive(Copy, Clone, Debug)]
+pub struct Gauge {
+    value: u32,
+    max: u32,
+    smooth: bool,
+}
+
+impl Gauge {
+
+    /// Returns a `Gauge` showing `value` against `max`.
+    pub const fn new(value: u32, max: u32) -> Gauge {
+        Gauge {value, max, smooth: false}
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// Sets the value.
+    pub fn set_value(&mut self, value: u32) {
+        self.value = value;
+    }
+
+    /// Returns the maximum.
+    pub fn max(&self) -> u32 {
+        self.max
+    }
+
+    /// Sets the maximum.
+    pub fn set_max(&mut self, max: u32) {
+        self.max = max;
+    }
+
+    /// Sets whether the last LED filled uses intermediate brightnesses.
+    pub fn set_smooth(&mut self, smooth: bool) {
+        self.smooth = smooth;
+    }
+
+}
+
+impl Render for Gauge {
+
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        fill_level(u64::from(self.value), u64::from(self.max), 25, (y*5 + x) as u64, self.smooth)
+    }
+
+}
+
+
+/// A sparkline showing the most recent of a series of samples.
+///
+/// Keeps the last `N` samples pushed. The five most recent are displayed as
+/// columns growing up from the bottom row, with the newest on the right.
+///
+/// The columns are scaled to the range of all `N` samples kept: the lowest
+/// shows as one LED and the highest as five. If all the samples are equal,
+/// each shows as one LED.
+#[derive(Copy, Clone, Debug)]
+pub struct Sparkline<const N: usize> {
+    samples: [i32; N],
+    // number of samples kept
+    len: usize,
+    // index in `samples` where the next sample will be stored
+    next: usize,
+    // lowest and highest samples kept
+    range: Option<(i32, i32)>,
+    smooth: bool,
+}
+
+impl<const N: usize> Sparkline<N> {
+
+    /// Returns a `Sparkline` with no samples.
+    pub const fn new() -> Sparkline<N> {
+        Sparkline {samples: [0; N], len: 0, next: 0, range: None, smooth: false}
+    }
+
+    /// Adds a sample, discarding the oldest if `N` samples are already kept.
+    pub fn push(&mut self, sample: i32) {
+        if N == 0 {return}
+        let discarded = if self.len == N {Some(self.samples[self.next])} else {None};
+        self.samples[self.next] = sample;
+        self.next = (self.next + 1) % N;
+        self.len = (self.len + 1).min(N);
+        self.range = match (self.range, discarded) {
+            (Some((low, high)), Some(old)) if old == low || old == high => self.scan_range(),
+            (Some((low, high)), _) => Some((low.min(sample), high.max(sample))),
+            (None, _) => Some((sample, sample)),
+        };
+    }
+
+    /// Discards all samples.
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.next = 0;
+        self.range = None;
+    }
+
+    /// Returns the number of samples kept.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Says whether there are no samples.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the sample `age` places before the most recent (so `0` gives
+    /// the most recent), if it's kept.
+    pub fn sample(&self, age: usize) -> Option<i32> {
+        if age >= self.len {return None}
+        Some(self.samples[(self.next + N - 1 - age) % N])
+    }
+
+    /// Returns the lowest and highest samples kept.
+    pub fn range(&self) -> Option<(i32, i32)> {
+        self.range
+    }
+
+    fn scan_range(&self) -> Option<(i32, i32)> {
+        (0..self.len).filter_map(|age| self.sample(age)).fold(None, |range, sample| {
+            match range {
+                None => Some((sample, sample)),
+                Some((low, high)) => Some((low.min(sample), high.max(sample))),
+            }
+        })
+    }
+
+    /// Sets whether the top of each column uses intermediate brightnesses.
+    pub fn set_smooth(&mut self, smooth: bool) {
+        self.smooth = smooth;
+    }
+
+}
+
+impl<const N: usize> Default for Sparkline<N> {
+
+    fn default() -> Sparkline<N> {
+        Sparkline::new()
+    }
+
+}
+
+impl<const N: usize> Render for Sparkline<N> {
+
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        let (sample, (low, high)) = match (self.sample(4 - x), self.range()) {
+            (Some(sample), Some(range)) => (sample, range),
+            _ => return 0,
+        };
+        let span = (high as i64 - low as i64) as u64;
+        let offset = (sample as i64 - low as i64) as u64;
+        // one LED for the lowest sample, plus up to four more
+        let (value, max) = if span == 0 {(1, 5)} else {(span + offset * 4, span * 5)};
+        fill_level(value, max, 5, 4 - y as u64, self.smooth)
+    }
+
+}
@@ -0,0 +1,212 @@
+//! Combining two images into one.
+//!
+//! Each type in this module implements [`Render`] by combining the
+//! brightness values of two other `Render`s, pixel by pixel. They hold
+//! references to the underlying images, so they don't copy any image data.
+//!
+//! Type             | Brightness of each pixel |
+//! ---------------- | ------------------------ |
+//! [`Overlay`]      | the top image's, where it's nonzero; otherwise the bottom image's |
+//! [`Masked`]       | the image's, where the mask is on; otherwise 0 |
+//! [`Max`]          | the greater of the two |
+//! [`Min`]          | the lesser of the two |
+//! [`Add`]          | the sum of the two, limited to `MAX_BRIGHTNESS` |
+//! [`Blend`]        | a weighted average of the two |
+//!
+//! # Example
+//!
+//! ```ignore
+//! use rmicrobit::graphics::compose::{Masked, Overlay};
+//! let cursor_on: bool = ...;
+//! if cursor_on {
+//!     frame.set(&Overlay::new(&CURSOR, &scroller));
+//! } else {
+//!     frame.set(&Masked::new(&scroller, &STATUS_AREA));
+//! }
+//! ```
+//!
+//! [`Render`]: crate::display::Render
+
+use tiny_led_matrix::{Render, MAX_BRIGHTNESS};
+use crate::graphics::image::BitImage;
+
+#[cfg(test)]
+#[path = "../../unit_tests/graphics/compose_tests.rs"]
+mod tests;
+
+
+/// Displays one image on top of another.
+///
+/// Pixels which are 0 in the top image show the bottom image.
+#[derive(Copy, Clone)]
+pub struct Overlay<'a, T: Render + ?Sized, B: Render + ?Sized> {
+    top: &'a T,
+    bottom: &'a B,
+}
+
+impl<'a, T: Render + ?Sized, B: Render + ?Sized> Overlay<'a, T, B> {
+
+    /// Returns an `Overlay` displaying `top` on top of `bottom`.
+    pub fn new(top: &'a T, bottom: &'a B) -> Overlay<'a, T, B> {
+        Overlay {top, bottom}
+    }
+
+}
+
+impl<T: Render + ?Sized, B: Render + ?Sized> Render for Overlay<'_, T, B> {
+
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        match self.top.brightness_at(x, y) {
+            0 => self.bottom.brightness_at(x, y),
+            brightness => brightness,
+        }
+    }
+
+}
+
+
+/// Displays only the parts of an image selected by a [`BitImage`].
+///
+/// Pixels which are off in the mask are 0.
+///
+/// [`BitImage`]: crate::graphics::image::BitImage
+#[derive(Copy, Clone)]
+pub struct Masked<'a, T: Render + ?Sized> {
+    image: &'a T,
+    mask: &'a BitImage,
+}
+
+impl<'a, T: Render + ?Sized> Masked<'a, T> {
+
+    /// Returns a `Masked` displaying the parts of `image` where `mask` is
+    /// on.
+    pub fn new(image: &'a T, mask: &'a BitImage) -> Masked<'a, T> {
+        Masked {image, mask}
+    }
+
+}
+
+impl<T: Render + ?Sized> Render for Masked<'_, T> {
+
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        if self.mask.pixel(x, y) {self.image.brightness_at(x, y)} else {0}
+    }
+
+}
+
+
+/// Displays the brighter of two images at each pixel.
+#[derive(Copy, Clone)]
+pub struct Max<'a, A: Render + ?Sized, B: Render + ?Sized> {
+    a: &'a A,
+    b: &'a B,
+}
+
+impl<'a, A: Render + ?Sized, B: Render + ?Sized> Max<'a, A, B> {
+
+    /// Returns a `Max` combining `a` and `b`.
+    pub fn new(a: &'a A, b: &'a B) -> Max<'a, A, B> {
+        Max {a, b}
+    }
+
+}
+
+impl<A: Render + ?Sized, B: Render + ?Sized> Render for Max<'_, A, B> {
+
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        self.a.brightness_at(x, y).max(self.b.brightness_at(x, y))
+    }
+
+}
+
+
+/// Displays the dimmer of two images at each pixel.
+#[derive(Copy, Clone)]
+pub struct Min<'a, A: Render + ?Sized, B: Render + ?Sized> {
+    a: &'a A,
+    b: &'a B,
+}
+
+impl<'a, A: Render + ?Sized, B: Render + ?Sized> Min<'a, A, B> {
+
+    /// Returns a `Min` combining `a` and `b`.
+    pub fn new(a: &'a A, b: &'a B) -> Min<'a, A, B> {
+        Min {a, b}
+    }
+
+}
+
+impl<A: Render + ?Sized, B: Render + ?Sized> Render for Min<'_, A, B> {
+
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        self.a.brightness_at(x, y).min(self.b.brightness_at(x, y))
+    }
+
+}
+
+
+/// Displays the sum of two images' brightnesses at each pixel.
+///
+/// The sum is limited to `MAX_BRIGHTNESS`.
+#[derive(Copy, Clone)]
+pub struct Add<'a, A: Render + ?Sized, B: Render + ?Sized> {
+    a: &'a A,
+    b: &'a B,
+}
+
+impl<'a, A: Render + ?Sized, B: Render + ?Sized> Add<'a, A, B> {
+
+    /// Returns an `Add` combining `a` and `b`.
+    pub fn new(a: &'a A, b: &'a B) -> Add<'a, A, B> {
+        Add {a, b}
+    }
+
+}
+
+impl<A: Render + ?Sized, B: Render + ?Sized> Render for Add<'_, A, B> {
+
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        self.a.brightness_at(x, y)
+            .saturating_add(self.b.brightness_at(x, y))
+            .min(MAX_BRIGHTNESS)
+    }
+
+}
+
+
+/// Displays a weighted average of two images.
+///
+/// The weight is given by an `alpha` value from 0 to 255: 0 displays only
+/// the first image, and 255 displays only the second. Brightnesses are
+/// rounded to the nearest level.
+#[derive(Copy, Clone)]
+pub struct Blend<'a, A: Render + ?Sized, B: Render + ?Sized> {
+    a: &'a A,
+    b: &'a B,
+    alpha: u8,
+}
+
+impl<'a, A: Render + ?Sized, B: Render + ?Sized> Blend<'a, A, B> {
+
+    /// Returns a `Blend` combining `a` and `b` with weight `alpha`.
+    pub fn new(a: &'a A, b: &'a B, alpha: u8) -> Blend<'a, A, B> {
+        Blend {a, b, alpha}
+    }
+
+}
+
+impl<A: Render + ?Sized, B: Render + ?Sized> Render for Blend<'_, A, B> {
+
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        blend(self.a.brightness_at(x, y), self.b.brightness_at(x, y), self.alpha)
+    }
+
+}
+
+/// Returns the weighted average of brightnesses `a` and `b`.
+///
+/// `alpha` 0 gives `a` and 255 gives `b`.
+pub(crate) const fn blend(a: u8, b: u8, alpha: u8) -> u8 {
+    let alpha = alpha as u16;
+    ((a as u16 * (255 - alpha) + b as u16 * alpha + 127) / 255) as u8
+}
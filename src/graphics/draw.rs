@@ -0,0 +1,322 @@
+//! Drawing lines and shapes on images.
+//!
+//! The [`Draw`] trait provides drawing operations for any image type with
+//! mutable greyscale pixels. It's implemented for [`GreyscaleImage`] and
+//! [`Canvas`].
+//!
+//! All operations take signed coordinates and clip to the image: the parts
+//! of a shape which fall outside the image aren't drawn.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use rmicrobit::graphics::draw::Draw;
+//! use rmicrobit::graphics::image::GreyscaleImage;
+//! let mut image = GreyscaleImage::blank();
+//! image.rect(0, 0, 5, 5, 3);
+//! image.line(0, 0, 4, 4, 9);
+//! image.flood_fill(3, 1, 6);
+//! ```
+//!
+//! [`Canvas`]: crate::graphics::canvas::Canvas
+//! [`GreyscaleImage`]: crate::graphics::image::GreyscaleImage
+
+use crate::graphics::canvas::Canvas;
+use crate::graphics::image::GreyscaleImage;
+
+#[cfg(test)]
+#[path = "../../unit_tests/graphics/draw_tests.rs"]
+mod tests;
+
+/// Drawing operations on an image with mutable greyscale pixels.
+///
+/// Implementors provide [`size()`], [`get_pixel()`] and [`put_pixel()`];
+/// the drawing operations are provided methods.
+///
+/// Brightnesses should be from 0 to `MAX_BRIGHTNESS`.
+///
+/// [`size()`]: Draw::size
+/// [`get_pixel()`]: Draw::get_pixel
+/// [`put_pixel()`]: Draw::put_pixel
+pub trait Draw {
+
+    /// Returns the width and height of the image, in pixels.
+    fn size(&self) -> (usize, usize);
+
+    /// Returns the brightness of the pixel at (x, y).
+    ///
+    /// The caller guarantees that (x, y) is within the image.
+    fn get_pixel(&self, x: usize, y: usize) -> u8;
+
+    /// Sets the brightness of the pixel at (x, y).
+    ///
+    /// The caller guarantees that (x, y) is within the image.
+    fn put_pixel(&mut self, x: usize, y: usize, brightness: u8);
+
+    /// Sets the brightness of the pixel at (x, y).
+    ///
+    /// Does nothing if (x, y) is outside the image.
+    fn plot(&mut self, x: isize, y: isize, brightness: u8) {
+        let (width, height) = self.size();
+        if x < 0 || y < 0 || x as usize >= width || y as usize >= height {return}
+        self.put_pixel(x as usize, y as usize, brightness);
+    }
+
+    /// Draws a straight line from (x0, y0) to (x1, y1), including both
+    /// ends.
+    ///
+    /// Uses Bresenham's algorithm.
+    fn line(&mut self, x0: isize, y0: isize, x1: isize, y1: isize, brightness: u8) {
+        let dx = (x1 - x0).abs();
+        let dy = -(y1 - y0).abs();
+        let step_x = (x1 - x0).signum();
+        let step_y = (y1 - y0).signum();
+        let (mut x, mut y) = (x0, y0);
+        let mut error = dx + dy;
+        loop {
+            self.plot(x, y, brightness);
+            if x == x1 && y == y1 {break}
+            let doubled = 2 * error;
+            if doubled >= dy {
+                error += dy;
+                x += step_x;
+            }
+            if doubled <= dx {
+                error += dx;
+                y += step_y;
+            }
+        }
+    }
+
+    /// Draws the outline of a `width`×`height` rectangle with its top-left
+    /// corner at (x, y).
+    ///
+    /// Does nothing if `width` or `height` is 0.
+    fn rect(&mut self, x: isize, y: isize, width: usize, height: usize, brightness: u8) {
+        if width == 0 || height == 0 {return}
+        let (right, bottom) = (x + width as isize - 1, y + height as isize - 1);
+        self.line(x, y, right, y, brightness);
+        self.line(x, bottom, right, bottom, brightness);
+        self.line(x, y, x, bottom, brightness);
+        self.line(right, y, right, bottom, brightness);
+    }
+
+    /// Fills a `width`×`height` rectangle with its top-left corner at
+    /// (x, y).
+    fn fill_rect(&mut self, x: isize, y: isize, width: usize, height: usize, brightness: u8) {
+        for py in y..y + height as isize {
+            for px in x..x + width as isize {
+                self.plot(px, py, brightness);
+            }
+        }
+    }
+
+    /// Draws the outline of a circle with centre (x, y) and the specified
+    /// radius.
+    ///
+    /// Uses the midpoint circle algorithm. A radius of 0 draws a single
+    /// pixel.
+    fn circle(&mut self, x: isize, y: isize, radius: usize, brightness: u8) {
+        for_circle_octant(radius, |dx, dy| {
+            for &(px, py) in &[
+                (dx, dy), (-dx, dy), (dx, -dy), (-dx, -dy),
+                (dy, dx), (-dy, dx), (dy, -dx), (-dy, -dx),
+            ] {
+                self.plot(x + px, y + py, brightness);
+            }
+        });
+    }
+
+    /// Fills a circle with centre (x, y) and the specified radius.
+    ///
+    /// The filled area is the same shape as the outline drawn by
+    /// [`circle()`](Draw::circle).
+    fn fill_circle(&mut self, x: isize, y: isize, radius: usize, brightness: u8) {
+        for_circle_octant(radius, |dx, dy| {
+            for &(half_width, py) in &[(dx, dy), (dx, -dy), (dy, dx), (dy, -dx)] {
+                self.line(x - half_width, y + py, x + half_width, y + py, brightness);
+            }
+        });
+    }
+
+    /// Sets the brightness of the region containing (x, y): the pixel at
+    /// (x, y), and all pixels of the same brightness connected to it
+    /// horizontally or vertically.
+    ///
+    /// Does nothing if (x, y) is outside the image.
+    ///
+    /// The region is filled a row at a time, keeping a small fixed-size
+    /// stack of places still to visit. While filling, the region's pixels
+    /// are set to a value which no other pixel in the image has, so the
+    /// image can hold any values. If the stack fills up, the rest of the
+    /// region is found by scanning the image.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the image contains all 256 possible pixel values (which
+    /// can only happen if it has more than 255 pixels, and holds values
+    /// above `MAX_BRIGHTNESS`).
+    fn flood_fill(&mut self, x: isize, y: isize, brightness: u8) {
+        let (width, height) = self.size();
+        if x < 0 || y < 0 || x as usize >= width || y as usize >= height {return}
+        let (x, y) = (x as usize, y as usize);
+        let target = self.get_pixel(x, y);
+        if target == brightness {return}
+        let marker = unused_value(self);
+        let mut seeds = Seeds::new();
+        seeds.push(x, y);
+        loop {
+            while let Some((x, y)) = seeds.pop() {
+                fill_span(self, x, y, target, marker, &mut seeds);
+            }
+            if !seeds.overflowed {break}
+            // Some places to visit were dropped; find them again as the
+            // unfilled pixels next to the region.
+            seeds.overflowed = false;
+            for py in 0..height {
+                for px in 0..width {
+                    if self.get_pixel(px, py) != target {continue}
+                    let marked_neighbour =
+                        (px > 0 && self.get_pixel(px - 1, py) == marker) ||
+                        (px + 1 < width && self.get_pixel(px + 1, py) == marker) ||
+                        (py > 0 && self.get_pixel(px, py - 1) == marker) ||
+                        (py + 1 < height && self.get_pixel(px, py + 1) == marker);
+                    if marked_neighbour {seeds.push(px, py)}
+                }
+            }
+        }
+        for py in 0..height {
+            for px in 0..width {
+                if self.get_pixel(px, py) == marker {
+                    self.put_pixel(px, py, brightness);
+                }
+            }
+        }
+    }
+
+}
+
+// The number of places still to visit that flood_fill() keeps track of.
+const SEED_CAPACITY: usize = 32;
+
+// A fixed-size stack of pixels for flood_fill() to visit. Pushing on to a
+// full stack drops the pixel and sets `overflowed`.
+struct Seeds {
+    seeds: [(usize, usize); SEED_CAPACITY],
+    len: usize,
+    overflowed: bool,
+}
+
+impl Seeds {
+
+    fn new() -> Seeds {
+        Seeds {seeds: [(0, 0); SEED_CAPACITY], len: 0, overflowed: false}
+    }
+
+    fn push(&mut self, x: usize, y: usize) {
+        if self.len == SEED_CAPACITY {
+            self.overflowed = true;
+        } else {
+            self.seeds[self.len] = (x, y);
+            self.len += 1;
+        }
+    }
+
+    fn pop(&mut self) -> Option<(usize, usize)> {
+        if self.len == 0 {return None}
+        self.len -= 1;
+        Some(self.seeds[self.len])
+    }
+
+}
+
+// Returns the highest value which no pixel of `image` has.
+fn unused_value<D: Draw + ?Sized>(image: &D) -> u8 {
+    let (width, height) = image.size();
+    let mut used = [0_u32; 8];
+    for y in 0..height {
+        for x in 0..width {
+            let value = image.get_pixel(x, y);
+            used[value as usize / 32] |= 1 << (value % 32);
+        }
+    }
+    (0..=u8::MAX).rev()
+        .find(|&value| used[value as usize / 32] & 1 << (value % 32) == 0)
+        .expect("image uses every pixel value")
+}
+
+// Sets the run of `target` pixels containing (x, y) to `marker`, and pushes
+// the start of each run of `target` pixels next to it in the rows above and
+// below. Does nothing if (x, y) isn't `target` (it may have been filled since
+// it was pushed).
+fn fill_span<D: Draw + ?Sized>(
+    image: &mut D, x: usize, y: usize, target: u8, marker: u8, seeds: &mut Seeds,
+) {
+    let (width, height) = image.size();
+    if image.get_pixel(x, y) != target {return}
+    let (mut left, mut right) = (x, x);
+    while left > 0 && image.get_pixel(left - 1, y) == target {left -= 1}
+    while right + 1 < width && image.get_pixel(right + 1, y) == target {right += 1}
+    for px in left..=right {
+        image.put_pixel(px, y, marker);
+    }
+    for py in [y.wrapping_sub(1), y + 1] {
+        if py >= height {continue}
+        let mut in_run = false;
+        for px in left..=right {
+            let is_target = image.get_pixel(px, py) == target;
+            if is_target && !in_run {seeds.push(px, py)}
+            in_run = is_target;
+        }
+    }
+}
+
+// Calls `f(dx, dy)` for each point of one octant of a circle of the
+// specified radius, from (radius, 0) to the diagonal.
+fn for_circle_octant(radius: usize, mut f: impl FnMut(isize, isize)) {
+    let mut dx = radius as isize;
+    let mut dy = 0;
+    let mut error = 1 - dx;
+    while dx >= dy {
+        f(dx, dy);
+        dy += 1;
+        if error < 0 {
+            error += 2 * dy + 1;
+        } else {
+            dx -= 1;
+            error += 2 * (dy - dx) + 1;
+        }
+    }
+}
+
+impl Draw for GreyscaleImage {
+
+    fn size(&self) -> (usize, usize) {
+        (5, 5)
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> u8 {
+        self.pixel(x, y)
+    }
+
+    fn put_pixel(&mut self, x: usize, y: usize, brightness: u8) {
+        self.set_pixel(x, y, brightness);
+    }
+
+}
+
+impl<const W: usize, const H: usize> Draw for Canvas<W, H> {
+
+    fn size(&self) -> (usize, usize) {
+        (W, H)
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> u8 {
+        self.pixel(x as isize, y as isize)
+    }
+
+    fn put_pixel(&mut self, x: usize, y: usize, brightness: u8) {
+        self.set_pixel(x as isize, y as isize, brightness);
+    }
+
+}
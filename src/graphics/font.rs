@@ -1,20 +1,38 @@
-//! A 5×5 ascii font.
+//! 5×5 fonts.
 //!
-//! This is a copy of the 'pendolino' font from the [micro:bit runtime][dal].
+//! A [`Font`] maps characters to 5×5 [`BitImage`] glyphs.
+//!
+//! This module provides two fonts:
+//!
+//! - [`Pendolino`]: a copy of the 'pendolino' font from the [micro:bit
+//!   runtime][dal], covering the ascii printable characters, extended with
+//!   glyphs for the Latin-1 accented letters and symbols;
+//! - [`Compact`]: a font of 3×5 glyphs, covering digits, capital letters
+//!   (lower-case letters are shown as capitals) and common punctuation.
+//!
+//! The text scrollers in [`scrolling_text`] use `Pendolino` unless you
+//! choose a different font.
 //!
 //! [dal]: https://lancaster-university.github.io/microbit-docs/
+//! [`scrolling_text`]: crate::graphics::scrolling_text
 
+mod compact;
+mod latin1;
 mod pendolino;
 
 use crate::graphics::image::BitImage;
 
+#[cfg(test)]
+#[path = "../../unit_tests/graphics/font_tests.rs"]
+mod tests;
+
 /// Index of the first character in the standard font
 pub const PRINTABLE_START: usize = 32;
 
 /// Number of characters in the standard font
 pub const PRINTABLE_COUNT: usize = 95;
 
-const UNKNOWN: BitImage = BitImage::new(&[
+static UNKNOWN: BitImage = BitImage::new(&[
     [1, 1, 1, 1, 1],
     [1, 0, 0, 0, 1],
     [1, 0, 0, 0, 1],
@@ -23,19 +41,114 @@ const UNKNOWN: BitImage = BitImage::new(&[
 ]);
 
 
+/// A mapping from characters to 5×5 glyphs.
+pub trait Font {
+
+    /// Returns the glyph for the specified character, if the font has one.
+    fn glyph(&self, c: char) -> Option<&'static BitImage>;
+
+    /// Returns the glyph used for characters which aren't in the font.
+    ///
+    /// The default implementation returns a 'hollow square' image.
+    fn fallback(&self) -> &'static BitImage {
+        &UNKNOWN
+    }
+
+    /// Returns the glyph for the specified character, or the fallback glyph
+    /// if the font doesn't have one.
+    fn character(&self, c: char) -> &'static BitImage {
+        self.glyph(c).unwrap_or_else(|| self.fallback())
+    }
+
+    /// Returns the first column and number of columns used by the glyph for
+    /// the specified character.
+    ///
+    /// This is used for proportional spacing.
+    ///
+    /// The default implementation examines the glyph to find its blank
+    /// columns. Blank glyphs (such as space) are treated as
+    /// [`PROPORTIONAL_BLANK_WIDTH`] columns wide.
+    ///
+    /// [`PROPORTIONAL_BLANK_WIDTH`]: crate::graphics::scrolling::PROPORTIONAL_BLANK_WIDTH
+    fn glyph_extent(&self, c: char) -> (usize, usize) {
+        glyph_extent(self.character(c))
+    }
+
+}
+
+/// Returns the first column and number of columns used by `glyph`.
+///
+/// See [`Font::glyph_extent()`].
+pub fn glyph_extent(glyph: &BitImage) -> (usize, usize) {
+    let used = glyph.used_columns();
+    if used == 0 {
+        return (0, crate::graphics::scrolling::PROPORTIONAL_BLANK_WIDTH);
+    }
+    let first = used.trailing_zeros() as usize;
+    let last = 7 - used.leading_zeros() as usize;
+    (first, last + 1 - first)
+}
+
+
+/// The 'pendolino' font from the [micro:bit runtime][dal].
+///
+/// This covers the ascii printable characters (`' '` to `'~'`), the Latin-1
+/// Supplement characters (`'\u{A0}'` to `'ÿ'`, including accented letters
+/// and symbols such as `°`, `£` and `×`), and `€`.
+///
+/// The glyphs outside ascii aren't part of the micro:bit runtime's font.
+///
+/// [dal]: https://lancaster-university.github.io/microbit-docs/
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Pendolino;
+
+impl Font for Pendolino {
+
+    fn glyph(&self, c: char) -> Option<&'static BitImage> {
+        let index = c as usize;
+        if !(PRINTABLE_START..PRINTABLE_START + PRINTABLE_COUNT).contains(&index) {
+            return self::latin1::glyph(c);
+        }
+        Some(&self::pendolino::PENDOLINO3[index - PRINTABLE_START])
+    }
+
+}
+
+
+/// A font of 3×5 glyphs.
+///
+/// This covers the digits, the letters (lower-case letters are shown as
+/// capitals), space, and the following punctuation:
+///
+/// ```text
+/// ! " ' ( ) + , - . / : < = > ? _ %
+/// ```
+///
+/// Each glyph uses the middle three columns.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Compact;
+
+impl Font for Compact {
+
+    fn glyph(&self, c: char) -> Option<&'static BitImage> {
+        self::compact::glyph(c)
+    }
+
+}
+
+
 /// Returns an image representing the requested ascii character.
 ///
 /// If the requested character isn't printable, returns a 'hollow square' image.
 ///
+/// This uses the [`Pendolino`] font.
+///
 /// # Example
 ///
 /// `font::character(b'x')`
 pub fn character(index: u8) -> &'static BitImage {
-    let index = index as usize;
-    if !(PRINTABLE_START..PRINTABLE_START + PRINTABLE_COUNT).contains(&index) {
-        return &UNKNOWN;
-    }
-    &self::pendolino::PENDOLINO3[index - PRINTABLE_START]
+    if !index.is_ascii() {return &UNKNOWN}
+    Pendolino.character(index as char)
 }
 
 const fn font_entry(data: [u8; 5]) -> BitImage {
@@ -56,4 +169,3 @@ const fn font_entry(data: [u8; 5]) -> BitImage {
         row_bits(data[4]),
     ])
 }
-
@@ -0,0 +1,94 @@
+use crate::graphics::image::BitImage;
+
+// Each glyph is five rows of three bits; the most-significant bit is the
+// leftmost column. Glyphs are placed in the middle three columns.
+const fn compact_entry(data: [u8; 5]) -> BitImage {
+    const fn row_bits(bits: u8) -> [u8; 5] {[
+        0,
+        ((bits & 1<<2) != 0) as u8,
+        ((bits & 1<<1) != 0) as u8,
+        ((bits & 1<<0) != 0) as u8,
+        0,
+    ]}
+    BitImage::new(&[
+        row_bits(data[0]),
+        row_bits(data[1]),
+        row_bits(data[2]),
+        row_bits(data[3]),
+        row_bits(data[4]),
+    ])
+}
+
+static DIGITS: [BitImage; 10] = [
+    compact_entry([0b111, 0b101, 0b101, 0b101, 0b111]),
+    compact_entry([0b010, 0b110, 0b010, 0b010, 0b111]),
+    compact_entry([0b111, 0b001, 0b111, 0b100, 0b111]),
+    compact_entry([0b111, 0b001, 0b011, 0b001, 0b111]),
+    compact_entry([0b101, 0b101, 0b111, 0b001, 0b001]),
+    compact_entry([0b111, 0b100, 0b111, 0b001, 0b111]),
+    compact_entry([0b111, 0b100, 0b111, 0b101, 0b111]),
+    compact_entry([0b111, 0b001, 0b001, 0b010, 0b010]),
+    compact_entry([0b111, 0b101, 0b111, 0b101, 0b111]),
+    compact_entry([0b111, 0b101, 0b111, 0b001, 0b111]),
+];
+
+static LETTERS: [BitImage; 26] = [
+    compact_entry([0b010, 0b101, 0b111, 0b101, 0b101]),
+    compact_entry([0b110, 0b101, 0b110, 0b101, 0b110]),
+    compact_entry([0b011, 0b100, 0b100, 0b100, 0b011]),
+    compact_entry([0b110, 0b101, 0b101, 0b101, 0b110]),
+    compact_entry([0b111, 0b100, 0b110, 0b100, 0b111]),
+    compact_entry([0b111, 0b100, 0b110, 0b100, 0b100]),
+    compact_entry([0b011, 0b100, 0b101, 0b101, 0b011]),
+    compact_entry([0b101, 0b101, 0b111, 0b101, 0b101]),
+    compact_entry([0b111, 0b010, 0b010, 0b010, 0b111]),
+    compact_entry([0b001, 0b001, 0b001, 0b101, 0b010]),
+    compact_entry([0b101, 0b101, 0b110, 0b101, 0b101]),
+    compact_entry([0b100, 0b100, 0b100, 0b100, 0b111]),
+    compact_entry([0b101, 0b111, 0b111, 0b101, 0b101]),
+    compact_entry([0b110, 0b101, 0b101, 0b101, 0b101]),
+    compact_entry([0b010, 0b101, 0b101, 0b101, 0b010]),
+    compact_entry([0b110, 0b101, 0b110, 0b100, 0b100]),
+    compact_entry([0b010, 0b101, 0b101, 0b110, 0b011]),
+    compact_entry([0b110, 0b101, 0b110, 0b101, 0b101]),
+    compact_entry([0b011, 0b100, 0b010, 0b001, 0b110]),
+    compact_entry([0b111, 0b010, 0b010, 0b010, 0b010]),
+    compact_entry([0b101, 0b101, 0b101, 0b101, 0b111]),
+    compact_entry([0b101, 0b101, 0b101, 0b101, 0b010]),
+    compact_entry([0b101, 0b101, 0b111, 0b111, 0b101]),
+    compact_entry([0b101, 0b101, 0b010, 0b101, 0b101]),
+    compact_entry([0b101, 0b101, 0b010, 0b010, 0b010]),
+    compact_entry([0b111, 0b001, 0b010, 0b100, 0b111]),
+];
+
+static PUNCTUATION: [(char, BitImage); 18] = [
+    (' ', compact_entry([0b000, 0b000, 0b000, 0b000, 0b000])),
+    ('!', compact_entry([0b010, 0b010, 0b010, 0b000, 0b010])),
+    ('"', compact_entry([0b101, 0b101, 0b000, 0b000, 0b000])),
+    ('%', compact_entry([0b101, 0b001, 0b010, 0b100, 0b101])),
+    ('\'', compact_entry([0b010, 0b010, 0b000, 0b000, 0b000])),
+    ('(', compact_entry([0b001, 0b010, 0b010, 0b010, 0b001])),
+    (')', compact_entry([0b100, 0b010, 0b010, 0b010, 0b100])),
+    ('+', compact_entry([0b000, 0b010, 0b111, 0b010, 0b000])),
+    (',', compact_entry([0b000, 0b000, 0b000, 0b010, 0b100])),
+    ('-', compact_entry([0b000, 0b000, 0b111, 0b000, 0b000])),
+    ('.', compact_entry([0b000, 0b000, 0b000, 0b000, 0b010])),
+    ('/', compact_entry([0b001, 0b001, 0b010, 0b100, 0b100])),
+    (':', compact_entry([0b000, 0b010, 0b000, 0b010, 0b000])),
+    ('<', compact_entry([0b001, 0b010, 0b100, 0b010, 0b001])),
+    ('=', compact_entry([0b000, 0b111, 0b000, 0b111, 0b000])),
+    ('>', compact_entry([0b100, 0b010, 0b001, 0b010, 0b100])),
+    ('?', compact_entry([0b110, 0b001, 0b010, 0b000, 0b010])),
+    ('_', compact_entry([0b000, 0b000, 0b000, 0b000, 0b111])),
+];
+
+pub(super) fn glyph(c: char) -> Option<&'static BitImage> {
+    match c {
+        '0'..='9' => Some(&DIGITS[c as usize - '0' as usize]),
+        'A'..='Z' => Some(&LETTERS[c as usize - 'A' as usize]),
+        'a'..='z' => Some(&LETTERS[c as usize - 'a' as usize]),
+        _ => PUNCTUATION.iter()
+            .find(|(glyph_char, _)| *glyph_char == c)
+            .map(|(_, image)| image),
+    }
+}
@@ -0,0 +1,222 @@
+use crate::graphics::font::font_entry;
+use crate::graphics::image::BitImage;
+
+// Glyphs for the Latin-1 Supplement block (U+00A0 to U+00FF), and the euro
+// sign, in the same style as the pendolino font.
+//
+// Each glyph is five rows of five bits; the most-significant bit is the
+// leftmost column. Accents on capital letters use the top row, so the letter
+// itself is squeezed into the remaining four rows. Where the accent would
+// merge into the letter (on U, and on lower-case o and u), a blank row
+// separates them and the letter has three rows.
+//
+// Every glyph is different from the others, except that the no-break space
+// and soft hyphen look like ' ' and '-', '×' looks like '*' (which is drawn
+// as a cross), and '¡' looks like 'i'.
+
+const LATIN1_START: usize = 0xA0;
+
+static LATIN1: [BitImage; 96] = [
+    // U+00A0 no-break space
+    font_entry([0b00000, 0b00000, 0b00000, 0b00000, 0b00000]),
+    // U+00A1 ¡
+    font_entry([0b01000, 0b00000, 0b01000, 0b01000, 0b01000]),
+    // U+00A2 ¢
+    font_entry([0b00100, 0b01110, 0b10100, 0b01110, 0b00100]),
+    // U+00A3 £
+    font_entry([0b00110, 0b01000, 0b11100, 0b01000, 0b11110]),
+    // U+00A4 ¤
+    font_entry([0b10001, 0b01110, 0b01010, 0b01110, 0b10001]),
+    // U+00A5 ¥
+    font_entry([0b10001, 0b01010, 0b11111, 0b00100, 0b00100]),
+    // U+00A6 ¦
+    font_entry([0b01000, 0b01000, 0b00000, 0b01000, 0b01000]),
+    // U+00A7 §
+    font_entry([0b01110, 0b11000, 0b01110, 0b00110, 0b11100]),
+    // U+00A8 ¨
+    font_entry([0b01010, 0b00000, 0b00000, 0b00000, 0b00000]),
+    // U+00A9 ©
+    font_entry([0b01110, 0b10011, 0b10101, 0b10011, 0b01110]),
+    // U+00AA ª
+    font_entry([0b01100, 0b10100, 0b01110, 0b00000, 0b11110]),
+    // U+00AB «
+    font_entry([0b00000, 0b01001, 0b10010, 0b01001, 0b00000]),
+    // U+00AC ¬
+    font_entry([0b00000, 0b00000, 0b11110, 0b00010, 0b00000]),
+    // U+00AD soft hyphen
+    font_entry([0b00000, 0b00000, 0b01110, 0b00000, 0b00000]),
+    // U+00AE ®
+    font_entry([0b01110, 0b11011, 0b10111, 0b10101, 0b01110]),
+    // U+00AF ¯
+    font_entry([0b11110, 0b00000, 0b00000, 0b00000, 0b00000]),
+    // U+00B0 °
+    font_entry([0b01100, 0b10010, 0b01100, 0b00000, 0b00000]),
+    // U+00B1 ±
+    font_entry([0b00100, 0b01110, 0b00100, 0b00000, 0b01110]),
+    // U+00B2 ²
+    font_entry([0b11000, 0b00100, 0b01000, 0b11100, 0b00000]),
+    // U+00B3 ³
+    font_entry([0b11000, 0b00100, 0b01000, 0b00100, 0b11000]),
+    // U+00B4 ´
+    font_entry([0b00010, 0b00100, 0b00000, 0b00000, 0b00000]),
+    // U+00B5 µ
+    font_entry([0b00000, 0b10010, 0b10010, 0b11100, 0b10000]),
+    // U+00B6 ¶
+    font_entry([0b01111, 0b11101, 0b01101, 0b00101, 0b00101]),
+    // U+00B7 ·
+    font_entry([0b00000, 0b00000, 0b01000, 0b00000, 0b00000]),
+    // U+00B8 ¸
+    font_entry([0b00000, 0b00000, 0b00000, 0b00100, 0b01100]),
+    // U+00B9 ¹
+    font_entry([0b01000, 0b11000, 0b01000, 0b11100, 0b00000]),
+    // U+00BA º
+    font_entry([0b01000, 0b10100, 0b01000, 0b00000, 0b11100]),
+    // U+00BB »
+    font_entry([0b00000, 0b10010, 0b01001, 0b10010, 0b00000]),
+    // U+00BC ¼
+    font_entry([0b10000, 0b10010, 0b00100, 0b01010, 0b10111]),
+    // U+00BD ½
+    font_entry([0b10000, 0b10010, 0b00100, 0b01011, 0b10011]),
+    // U+00BE ¾
+    font_entry([0b11000, 0b01010, 0b11100, 0b01010, 0b10111]),
+    // U+00BF ¿
+    font_entry([0b00100, 0b00000, 0b01100, 0b10001, 0b01110]),
+    // U+00C0 À
+    font_entry([0b10000, 0b01100, 0b10010, 0b11110, 0b10010]),
+    // U+00C1 Á
+    font_entry([0b00010, 0b01100, 0b10010, 0b11110, 0b10010]),
+    // U+00C2 Â
+    font_entry([0b01100, 0b01100, 0b10010, 0b11110, 0b10010]),
+    // U+00C3 Ã
+    font_entry([0b11110, 0b01100, 0b10010, 0b11110, 0b10010]),
+    // U+00C4 Ä
+    font_entry([0b10010, 0b01100, 0b10010, 0b11110, 0b10010]),
+    // U+00C5 Å
+    font_entry([0b01100, 0b01100, 0b11110, 0b10010, 0b10010]),
+    // U+00C6 Æ
+    font_entry([0b01111, 0b10100, 0b11110, 0b10100, 0b10111]),
+    // U+00C7 Ç
+    font_entry([0b01110, 0b10000, 0b10000, 0b01110, 0b00100]),
+    // U+00C8 È
+    font_entry([0b10000, 0b11110, 0b11100, 0b10000, 0b11110]),
+    // U+00C9 É
+    font_entry([0b00010, 0b11110, 0b11100, 0b10000, 0b11110]),
+    // U+00CA Ê
+    font_entry([0b01100, 0b11110, 0b11100, 0b10000, 0b11110]),
+    // U+00CB Ë
+    font_entry([0b10010, 0b11110, 0b11100, 0b10000, 0b11110]),
+    // U+00CC Ì
+    font_entry([0b10000, 0b11100, 0b01000, 0b01000, 0b11100]),
+    // U+00CD Í
+    font_entry([0b00100, 0b11100, 0b01000, 0b01000, 0b11100]),
+    // U+00CE Î
+    font_entry([0b01000, 0b11100, 0b01000, 0b01000, 0b11100]),
+    // U+00CF Ï
+    font_entry([0b10100, 0b11100, 0b01000, 0b01000, 0b11100]),
+    // U+00D0 Ð
+    font_entry([0b11100, 0b10010, 0b11010, 0b10010, 0b11100]),
+    // U+00D1 Ñ
+    font_entry([0b11110, 0b10010, 0b11010, 0b10110, 0b10010]),
+    // U+00D2 Ò
+    font_entry([0b10000, 0b01100, 0b10010, 0b10010, 0b01100]),
+    // U+00D3 Ó
+    font_entry([0b00010, 0b01100, 0b10010, 0b10010, 0b01100]),
+    // U+00D4 Ô
+    font_entry([0b01100, 0b01100, 0b10010, 0b10010, 0b01100]),
+    // U+00D5 Õ
+    font_entry([0b11110, 0b01100, 0b10010, 0b10010, 0b01100]),
+    // U+00D6 Ö
+    font_entry([0b10010, 0b01100, 0b10010, 0b10010, 0b01100]),
+    // U+00D7 ×
+    font_entry([0b00000, 0b01010, 0b00100, 0b01010, 0b00000]),
+    // U+00D8 Ø
+    font_entry([0b01110, 0b10011, 0b10101, 0b11001, 0b01110]),
+    // U+00D9 Ù
+    font_entry([0b10000, 0b00000, 0b10010, 0b10010, 0b01100]),
+    // U+00DA Ú
+    font_entry([0b00010, 0b00000, 0b10010, 0b10010, 0b01100]),
+    // U+00DB Û
+    font_entry([0b01100, 0b00000, 0b10010, 0b10010, 0b01100]),
+    // U+00DC Ü
+    font_entry([0b10010, 0b00000, 0b10010, 0b10010, 0b01100]),
+    // U+00DD Ý
+    font_entry([0b00010, 0b10001, 0b01010, 0b00100, 0b00100]),
+    // U+00DE Þ
+    font_entry([0b10000, 0b11110, 0b10001, 0b11110, 0b10000]),
+    // U+00DF ß
+    font_entry([0b01100, 0b10010, 0b10100, 0b10010, 0b10100]),
+    // U+00E0 à
+    font_entry([0b10000, 0b01110, 0b10010, 0b10010, 0b01111]),
+    // U+00E1 á
+    font_entry([0b00010, 0b01110, 0b10010, 0b10010, 0b01111]),
+    // U+00E2 â
+    font_entry([0b01100, 0b01110, 0b10010, 0b10010, 0b01111]),
+    // U+00E3 ã
+    font_entry([0b11110, 0b01110, 0b10010, 0b10010, 0b01111]),
+    // U+00E4 ä
+    font_entry([0b10010, 0b01110, 0b10010, 0b10010, 0b01111]),
+    // U+00E5 å
+    font_entry([0b01100, 0b00000, 0b01110, 0b10010, 0b01111]),
+    // U+00E6 æ
+    font_entry([0b00000, 0b01110, 0b11111, 0b10100, 0b01111]),
+    // U+00E7 ç
+    font_entry([0b00000, 0b01110, 0b10000, 0b01110, 0b00100]),
+    // U+00E8 è
+    font_entry([0b10000, 0b01100, 0b11110, 0b10000, 0b01110]),
+    // U+00E9 é
+    font_entry([0b00010, 0b01100, 0b11110, 0b10000, 0b01110]),
+    // U+00EA ê
+    font_entry([0b01100, 0b01100, 0b11110, 0b10000, 0b01110]),
+    // U+00EB ë
+    font_entry([0b10010, 0b01100, 0b11110, 0b10000, 0b01110]),
+    // U+00EC ì
+    font_entry([0b10000, 0b00000, 0b01000, 0b01000, 0b01000]),
+    // U+00ED í
+    font_entry([0b00100, 0b00000, 0b01000, 0b01000, 0b01000]),
+    // U+00EE î
+    font_entry([0b01000, 0b10100, 0b01000, 0b01000, 0b01000]),
+    // U+00EF ï
+    font_entry([0b10100, 0b00000, 0b01000, 0b01000, 0b01000]),
+    // U+00F0 ð
+    font_entry([0b01100, 0b00110, 0b01110, 0b10010, 0b01100]),
+    // U+00F1 ñ
+    font_entry([0b11110, 0b11100, 0b10010, 0b10010, 0b10010]),
+    // U+00F2 ò
+    font_entry([0b10000, 0b00000, 0b01100, 0b10010, 0b01100]),
+    // U+00F3 ó
+    font_entry([0b00010, 0b00000, 0b01100, 0b10010, 0b01100]),
+    // U+00F4 ô
+    font_entry([0b01100, 0b00000, 0b01100, 0b10010, 0b01100]),
+    // U+00F5 õ
+    font_entry([0b11110, 0b00000, 0b01100, 0b10010, 0b01100]),
+    // U+00F6 ö
+    font_entry([0b10010, 0b00000, 0b01100, 0b10010, 0b01100]),
+    // U+00F7 ÷
+    font_entry([0b00100, 0b00000, 0b11111, 0b00000, 0b00100]),
+    // U+00F8 ø
+    font_entry([0b00000, 0b01110, 0b10110, 0b11010, 0b01110]),
+    // U+00F9 ù
+    font_entry([0b10000, 0b00000, 0b10010, 0b10010, 0b01111]),
+    // U+00FA ú
+    font_entry([0b00010, 0b00000, 0b10010, 0b10010, 0b01111]),
+    // U+00FB û
+    font_entry([0b01100, 0b00000, 0b10010, 0b10010, 0b01111]),
+    // U+00FC ü
+    font_entry([0b10010, 0b00000, 0b10010, 0b10010, 0b01111]),
+    // U+00FD ý
+    font_entry([0b00010, 0b10001, 0b01010, 0b00100, 0b11000]),
+    // U+00FE þ
+    font_entry([0b10000, 0b11100, 0b10010, 0b11100, 0b10000]),
+    // U+00FF ÿ
+    font_entry([0b01010, 0b10001, 0b01010, 0b00100, 0b11000]),
+];
+
+static EURO: BitImage = font_entry([0b00111, 0b01000, 0b11110, 0b01000, 0b00111]);
+
+pub(super) fn glyph(c: char) -> Option<&'static BitImage> {
+    match c {
+        '\u{A0}'..='\u{FF}' => Some(&LATIN1[c as usize - LATIN1_START]),
+        '€' => Some(&EURO),
+        _ => None,
+    }
+}
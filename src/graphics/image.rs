@@ -1,6 +1,11 @@
 //! Static 5×5 greyscale and black-and-white images.
 
 use tiny_led_matrix::{Render, MAX_BRIGHTNESS};
+use crate::graphics::transform::{shift_source, Transform};
+
+#[cfg(test)]
+#[path = "../../unit_tests/graphics/image_tests.rs"]
+mod tests;
 
 /// A 5×5 image supporting the full range of brightnesses for each LED.
 ///
@@ -18,6 +23,11 @@ impl GreyscaleImage {
     /// The data should be an array of 5 rows (top first), each of which is an
     /// array of 5 brightness values (left first).
     ///
+    /// See also [`greyscale_image!`], which makes a `GreyscaleImage` from a
+    /// string and checks it at compile time.
+    ///
+    /// [`greyscale_image!`]: crate::greyscale_image
+    ///
     /// # Example
     ///
     /// ```
@@ -37,6 +47,139 @@ impl GreyscaleImage {
         GreyscaleImage([[0; 5]; 5])
     }
 
+    /// Returns a new GreyscaleImage with all pixels set to `brightness`.
+    pub const fn filled(brightness: u8) -> GreyscaleImage {
+        GreyscaleImage([[brightness; 5]; 5])
+    }
+
+    /// Constructs a GreyscaleImage from a BitImage.
+    ///
+    /// Pixels which are on in the BitImage have brightness `MAX_BRIGHTNESS`.
+    pub const fn from_bit_image(image: &BitImage) -> GreyscaleImage {
+        let mut data = [[0; 5]; 5];
+        let mut y = 0;
+        while y < 5 {
+            let mut x = 0;
+            while x < 5 {
+                if image.pixel(x, y) {
+                    data[y][x] = MAX_BRIGHTNESS;
+                }
+                x += 1;
+            }
+            y += 1;
+        }
+        GreyscaleImage(data)
+    }
+
+    /// Returns the brightness of the pixel at (x, y).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` or `y` is greater than 4.
+    pub const fn pixel(&self, x: usize, y: usize) -> u8 {
+        self.0[y][x]
+    }
+
+    /// Sets the brightness of the pixel at (x, y).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` or `y` is greater than 4.
+    pub fn set_pixel(&mut self, x: usize, y: usize, brightness: u8) {
+        self.0[y][x] = brightness;
+    }
+
+    /// Sets all pixels to `brightness`.
+    pub fn fill(&mut self, brightness: u8) {
+        self.0 = [[brightness; 5]; 5];
+    }
+
+    /// Sets all pixels to 0.
+    pub fn clear(&mut self) {
+        self.fill(0);
+    }
+
+    /// Replaces each pixel's brightness *b* with `MAX_BRIGHTNESS` − *b*.
+    ///
+    /// Brightnesses greater than `MAX_BRIGHTNESS` become 0.
+    pub fn invert(&mut self) {
+        for row in self.0.iter_mut() {
+            for value in row.iter_mut() {
+                *value = MAX_BRIGHTNESS.saturating_sub(*value);
+            }
+        }
+    }
+
+    /// Sets the brightnesses of one row (`y`) of pixels.
+    ///
+    /// `row` gives the brightness values, left first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y` is greater than 4.
+    pub fn set_row(&mut self, y: usize, row: [u8; 5]) {
+        self.0[y] = row;
+    }
+
+    /// Sets the brightnesses of one column (`x`) of pixels.
+    ///
+    /// `column` gives the brightness values, top first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is greater than 4.
+    pub fn set_column(&mut self, x: usize, column: [u8; 5]) {
+        for (row, &value) in self.0.iter_mut().zip(column.iter()) {
+            row[x] = value;
+        }
+    }
+
+    /// Returns a copy of the image, rotated or flipped as specified by
+    /// `transform`.
+    pub const fn transformed(&self, transform: Transform) -> GreyscaleImage {
+        let mut data = [[0; 5]; 5];
+        let mut y = 0;
+        while y < 5 {
+            let mut x = 0;
+            while x < 5 {
+                let (sx, sy) = transform.source_coordinates(x, y);
+                data[y][x] = self.0[sy][sx];
+                x += 1;
+            }
+            y += 1;
+        }
+        GreyscaleImage(data)
+    }
+
+    /// Returns a copy of the image moved `dx` pixels to the right and `dy`
+    /// pixels down.
+    ///
+    /// Negative values move the image left or up. Pixels which don't
+    /// correspond to any in the original image have brightness `fill`.
+    pub const fn shifted(&self, dx: isize, dy: isize, fill: u8) -> GreyscaleImage {
+        let mut data = [[fill; 5]; 5];
+        let mut y = 0;
+        while y < 5 {
+            let mut x = 0;
+            while x < 5 {
+                if let Some((sx, sy)) = shift_source(x, y, dx, dy) {
+                    data[y][x] = self.0[sy][sx];
+                }
+                x += 1;
+            }
+            y += 1;
+        }
+        GreyscaleImage(data)
+    }
+
+}
+
+impl From<BitImage> for GreyscaleImage {
+
+    fn from(image: BitImage) -> GreyscaleImage {
+        GreyscaleImage::from_bit_image(&image)
+    }
+
 }
 
 impl Render for GreyscaleImage {
@@ -71,6 +214,11 @@ impl BitImage {
     /// The data should be an array of 5 rows (top first), each of which is an
     /// array of 5 values (left first). Each value should be either 0 or 1.
     ///
+    /// Other values aren't rejected. See [`bit_image!`] for a form which is
+    /// checked at compile time.
+    ///
+    /// [`bit_image!`]: crate::bit_image
+    ///
     /// # Example
     ///
     /// ```
@@ -83,7 +231,6 @@ impl BitImage {
     /// ]);
     /// ```
     pub const fn new(im: &[[u8; 5]; 5]) -> BitImage {
-        // FIXME: can we reject values other than 0 or 1?
         const fn row_byte(row: [u8; 5]) -> u8 {
             row[0] | row[1]<<1 | row[2]<<2 | row[3]<<3 | row[4]<<4
         }
@@ -103,12 +250,156 @@ impl BitImage {
         BitImage([0; 5])
     }
 
+    /// Returns a new BitImage with all pixels on.
+    pub const fn filled() -> BitImage {
+        BitImage([0b11111; 5])
+    }
+
+    /// Constructs a BitImage from a GreyscaleImage.
+    ///
+    /// Pixels whose brightness is at least `threshold` are on; the others are
+    /// off.
+    pub const fn from_greyscale_image(image: &GreyscaleImage, threshold: u8) -> BitImage {
+        let mut data = [0; 5];
+        let mut y = 0;
+        while y < 5 {
+            let mut x = 0;
+            while x < 5 {
+                if image.pixel(x, y) >= threshold {
+                    data[y] |= 1<<x;
+                }
+                x += 1;
+            }
+            y += 1;
+        }
+        BitImage(data)
+    }
+
+    /// Says whether the pixel at (x, y) is on.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` or `y` is greater than 4.
+    pub const fn pixel(&self, x: usize, y: usize) -> bool {
+        assert!(x < 5, "x out of range");
+        self.0[y] & (1<<x) != 0
+    }
+
+    /// Returns a bitmask of the columns which have at least one pixel on.
+    ///
+    /// Bit *n* represents column *n* (counting from the left).
+    pub const fn used_columns(&self) -> u8 {
+        self.0[0] | self.0[1] | self.0[2] | self.0[3] | self.0[4]
+    }
+
+    /// Turns the pixel at (x, y) on or off.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` or `y` is greater than 4.
+    pub fn set_pixel(&mut self, x: usize, y: usize, on: bool) {
+        assert!(x < 5, "x out of range");
+        if on {
+            self.0[y] |= 1<<x;
+        } else {
+            self.0[y] &= !(1<<x);
+        }
+    }
+
+    /// Turns all pixels on.
+    pub fn fill(&mut self) {
+        self.0 = [0b11111; 5];
+    }
+
+    /// Turns all pixels off.
+    pub fn clear(&mut self) {
+        self.0 = [0; 5];
+    }
+
+    /// Turns each pixel which is on off, and vice versa.
+    pub fn invert(&mut self) {
+        for rowdata in self.0.iter_mut() {
+            *rowdata ^= 0b11111;
+        }
+    }
+
+    /// Sets one row (`y`) of pixels.
+    ///
+    /// `row` gives the values, left first. Each value should be either 0 or
+    /// 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y` is greater than 4.
+    pub fn set_row(&mut self, y: usize, row: [u8; 5]) {
+        for (x, &value) in row.iter().enumerate() {
+            self.set_pixel(x, y, value != 0);
+        }
+    }
+
+    /// Sets one column (`x`) of pixels.
+    ///
+    /// `column` gives the values, top first. Each value should be either 0
+    /// or 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is greater than 4.
+    pub fn set_column(&mut self, x: usize, column: [u8; 5]) {
+        for (y, &value) in column.iter().enumerate() {
+            self.set_pixel(x, y, value != 0);
+        }
+    }
+
+    /// Returns a copy of the image, rotated or flipped as specified by
+    /// `transform`.
+    pub const fn transformed(&self, transform: Transform) -> BitImage {
+        let mut data = [0; 5];
+        let mut y = 0;
+        while y < 5 {
+            let mut x = 0;
+            while x < 5 {
+                let (sx, sy) = transform.source_coordinates(x, y);
+                if self.pixel(sx, sy) {
+                    data[y] |= 1<<x;
+                }
+                x += 1;
+            }
+            y += 1;
+        }
+        BitImage(data)
+    }
+
+    /// Returns a copy of the image moved `dx` pixels to the right and `dy`
+    /// pixels down.
+    ///
+    /// Negative values move the image left or up. Pixels which don't
+    /// correspond to any in the original image are on if `fill` is true.
+    pub const fn shifted(&self, dx: isize, dy: isize, fill: bool) -> BitImage {
+        let mut data = [0; 5];
+        let mut y = 0;
+        while y < 5 {
+            let mut x = 0;
+            while x < 5 {
+                let on = match shift_source(x, y, dx, dy) {
+                    Some((sx, sy)) => self.pixel(sx, sy),
+                    None => fill,
+                };
+                if on {
+                    data[y] |= 1<<x;
+                }
+                x += 1;
+            }
+            y += 1;
+        }
+        BitImage(data)
+    }
+
 }
 
 impl Render for BitImage {
     fn brightness_at(&self, x: usize, y: usize) -> u8 {
-        let rowdata = self.0[y];
-        if rowdata & (1<<x) != 0 {MAX_BRIGHTNESS as u8} else {0}
+        if self.pixel(x, y) {MAX_BRIGHTNESS} else {0}
     }
 }
 
@@ -0,0 +1,186 @@
+//! The built-in images provided by MicroPython's `Image` class.
+//!
+//! Each image is a [`BitImage`] constant with the same name as in
+//! MicroPython (for example `Image.ARROW_NE` is [`ARROW_NE`]).
+//!
+//! [`ALL_CLOCKS`] and [`ALL_ARROWS`] contain the clock hands and arrows in
+//! clockwise order, starting from 12 o'clock and north respectively, as in
+//! MicroPython.
+//!
+//! Use `GreyscaleImage::from()` if you need a [`GreyscaleImage`] version.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use rmicrobit::graphics::images;
+//! display.set_image(&images::HAPPY);
+//! ```
+//!
+//! [`GreyscaleImage`]: crate::graphics::image::GreyscaleImage
+
+use crate::bit_image;
+use crate::graphics::image::BitImage;
+
+#[cfg(test)]
+#[path = "../../unit_tests/graphics/images_tests.rs"]
+mod tests;
+
+// Faces
+
+/// A heart (MicroPython's `Image.HEART`).
+pub const HEART: BitImage = bit_image!("09090:99999:99999:09990:00900");
+/// A small heart (MicroPython's `Image.HEART_SMALL`).
+pub const HEART_SMALL: BitImage = bit_image!("00000:09090:09990:00900:00000");
+/// A happy face (MicroPython's `Image.HAPPY`).
+pub const HAPPY: BitImage = bit_image!("00000:09090:00000:90009:09990");
+/// A smiling mouth (MicroPython's `Image.SMILE`).
+pub const SMILE: BitImage = bit_image!("00000:00000:00000:90009:09990");
+/// A sad face (MicroPython's `Image.SAD`).
+pub const SAD: BitImage = bit_image!("00000:09090:00000:09990:90009");
+/// A confused face (MicroPython's `Image.CONFUSED`).
+pub const CONFUSED: BitImage = bit_image!("00000:09090:00000:09090:90909");
+/// An angry face (MicroPython's `Image.ANGRY`).
+pub const ANGRY: BitImage = bit_image!("90009:09090:00000:99999:90909");
+/// A sleeping face (MicroPython's `Image.ASLEEP`).
+pub const ASLEEP: BitImage = bit_image!("00000:99099:00000:09990:00000");
+/// A surprised face (MicroPython's `Image.SURPRISED`).
+pub const SURPRISED: BitImage = bit_image!("09090:00000:00900:09090:00900");
+/// A silly face (MicroPython's `Image.SILLY`).
+pub const SILLY: BitImage = bit_image!("90009:00000:99999:00099:00099");
+/// A face wearing sunglasses (MicroPython's `Image.FABULOUS`).
+pub const FABULOUS: BitImage = bit_image!("99999:99099:00000:09090:09990");
+/// An unimpressed face (MicroPython's `Image.MEH`).
+pub const MEH: BitImage = bit_image!("09090:00000:00090:00900:09000");
+/// A tick (MicroPython's `Image.YES`).
+pub const YES: BitImage = bit_image!("00000:00009:00090:90900:09000");
+/// A cross (MicroPython's `Image.NO`).
+pub const NO: BitImage = bit_image!("90009:09090:00900:09090:90009");
+
+// Clock hands
+
+/// A clock hand pointing to 12 o'clock (MicroPython's `Image.CLOCK12`).
+pub const CLOCK12: BitImage = bit_image!("00900:00900:00900:00000:00000");
+/// A clock hand pointing to 1 o'clock (MicroPython's `Image.CLOCK1`).
+pub const CLOCK1: BitImage = bit_image!("00090:00090:00900:00000:00000");
+/// A clock hand pointing to 2 o'clock (MicroPython's `Image.CLOCK2`).
+pub const CLOCK2: BitImage = bit_image!("00000:00099:00900:00000:00000");
+/// A clock hand pointing to 3 o'clock (MicroPython's `Image.CLOCK3`).
+pub const CLOCK3: BitImage = bit_image!("00000:00000:00999:00000:00000");
+/// A clock hand pointing to 4 o'clock (MicroPython's `Image.CLOCK4`).
+pub const CLOCK4: BitImage = bit_image!("00000:00000:00900:00099:00000");
+/// A clock hand pointing to 5 o'clock (MicroPython's `Image.CLOCK5`).
+pub const CLOCK5: BitImage = bit_image!("00000:00000:00900:00090:00090");
+/// A clock hand pointing to 6 o'clock (MicroPython's `Image.CLOCK6`).
+pub const CLOCK6: BitImage = bit_image!("00000:00000:00900:00900:00900");
+/// A clock hand pointing to 7 o'clock (MicroPython's `Image.CLOCK7`).
+pub const CLOCK7: BitImage = bit_image!("00000:00000:00900:09000:09000");
+/// A clock hand pointing to 8 o'clock (MicroPython's `Image.CLOCK8`).
+pub const CLOCK8: BitImage = bit_image!("00000:00000:00900:99000:00000");
+/// A clock hand pointing to 9 o'clock (MicroPython's `Image.CLOCK9`).
+pub const CLOCK9: BitImage = bit_image!("00000:00000:99900:00000:00000");
+/// A clock hand pointing to 10 o'clock (MicroPython's `Image.CLOCK10`).
+pub const CLOCK10: BitImage = bit_image!("00000:99000:00900:00000:00000");
+/// A clock hand pointing to 11 o'clock (MicroPython's `Image.CLOCK11`).
+pub const CLOCK11: BitImage = bit_image!("09000:09000:00900:00000:00000");
+
+// Arrows
+
+/// An arrow pointing north (up) (MicroPython's `Image.ARROW_N`).
+pub const ARROW_N: BitImage = bit_image!("00900:09990:90909:00900:00900");
+/// An arrow pointing north-east (MicroPython's `Image.ARROW_NE`).
+pub const ARROW_NE: BitImage = bit_image!("00999:00099:00909:09000:90000");
+/// An arrow pointing east (right) (MicroPython's `Image.ARROW_E`).
+pub const ARROW_E: BitImage = bit_image!("00900:00090:99999:00090:00900");
+/// An arrow pointing south-east (MicroPython's `Image.ARROW_SE`).
+pub const ARROW_SE: BitImage = bit_image!("90000:09000:00909:00099:00999");
+/// An arrow pointing south (down) (MicroPython's `Image.ARROW_S`).
+pub const ARROW_S: BitImage = bit_image!("00900:00900:90909:09990:00900");
+/// An arrow pointing south-west (MicroPython's `Image.ARROW_SW`).
+pub const ARROW_SW: BitImage = bit_image!("00009:00090:90900:99000:99900");
+/// An arrow pointing west (left) (MicroPython's `Image.ARROW_W`).
+pub const ARROW_W: BitImage = bit_image!("00900:09000:99999:09000:00900");
+/// An arrow pointing north-west (MicroPython's `Image.ARROW_NW`).
+pub const ARROW_NW: BitImage = bit_image!("99900:99000:90900:00090:00009");
+
+// Shapes
+
+/// A triangle pointing up (MicroPython's `Image.TRIANGLE`).
+pub const TRIANGLE: BitImage = bit_image!("00000:00900:09090:99999:00000");
+/// A triangle in the bottom-left corner (MicroPython's `Image.TRIANGLE_LEFT`).
+pub const TRIANGLE_LEFT: BitImage = bit_image!("90000:99000:90900:90090:99999");
+/// A chessboard pattern (MicroPython's `Image.CHESSBOARD`).
+pub const CHESSBOARD: BitImage = bit_image!("09090:90909:09090:90909:09090");
+/// A diamond (MicroPython's `Image.DIAMOND`).
+pub const DIAMOND: BitImage = bit_image!("00900:09090:90009:09090:00900");
+/// A small diamond (MicroPython's `Image.DIAMOND_SMALL`).
+pub const DIAMOND_SMALL: BitImage = bit_image!("00000:00900:09090:00900:00000");
+/// A square (MicroPython's `Image.SQUARE`).
+pub const SQUARE: BitImage = bit_image!("99999:90009:90009:90009:99999");
+/// A small square (MicroPython's `Image.SQUARE_SMALL`).
+pub const SQUARE_SMALL: BitImage = bit_image!("00000:09990:09090:09990:00000");
+/// A target (MicroPython's `Image.TARGET`).
+pub const TARGET: BitImage = bit_image!("00900:09990:99099:09990:00900");
+
+// Animals
+
+/// A rabbit (MicroPython's `Image.RABBIT`).
+pub const RABBIT: BitImage = bit_image!("90900:90900:99990:99090:99990");
+/// A cow (MicroPython's `Image.COW`).
+pub const COW: BitImage = bit_image!("90009:90009:99999:09990:00900");
+/// A duck (MicroPython's `Image.DUCK`).
+pub const DUCK: BitImage = bit_image!("09900:99900:09999:09990:00000");
+/// A tortoise (MicroPython's `Image.TORTOISE`).
+pub const TORTOISE: BitImage = bit_image!("00000:09990:99999:09090:00000");
+/// A butterfly (MicroPython's `Image.BUTTERFLY`).
+pub const BUTTERFLY: BitImage = bit_image!("99099:99999:00900:99999:99099");
+/// A giraffe (MicroPython's `Image.GIRAFFE`).
+pub const GIRAFFE: BitImage = bit_image!("99000:09000:09000:09990:09090");
+/// A snake (MicroPython's `Image.SNAKE`).
+pub const SNAKE: BitImage = bit_image!("99000:99099:09090:09990:00000");
+
+// Music
+
+/// A crotchet (MicroPython's `Image.MUSIC_CROTCHET`).
+pub const MUSIC_CROTCHET: BitImage = bit_image!("00900:00900:00900:99900:99900");
+/// A quaver (MicroPython's `Image.MUSIC_QUAVER`).
+pub const MUSIC_QUAVER: BitImage = bit_image!("00900:00990:00909:99900:99900");
+/// A pair of quavers (MicroPython's `Image.MUSIC_QUAVERS`).
+pub const MUSIC_QUAVERS: BitImage = bit_image!("09999:09009:09009:99099:99099");
+
+// Other
+
+/// A pitchfork (MicroPython's `Image.PITCHFORK`).
+pub const PITCHFORK: BitImage = bit_image!("90909:90909:99999:00900:00900");
+/// A Christmas tree (MicroPython's `Image.XMAS`).
+pub const XMAS: BitImage = bit_image!("00900:09990:00900:09990:99999");
+/// Pac-Man (MicroPython's `Image.PACMAN`).
+pub const PACMAN: BitImage = bit_image!("09999:99090:99900:99990:09999");
+/// A T-shirt (MicroPython's `Image.TSHIRT`).
+pub const TSHIRT: BitImage = bit_image!("99099:99999:09990:09990:09990");
+/// A roller skate (MicroPython's `Image.ROLLERSKATE`).
+pub const ROLLERSKATE: BitImage = bit_image!("00099:00099:99999:99999:09090");
+/// A house (MicroPython's `Image.HOUSE`).
+pub const HOUSE: BitImage = bit_image!("00900:09990:99999:09990:09090");
+/// A stick figure (MicroPython's `Image.STICKFIGURE`).
+pub const STICKFIGURE: BitImage = bit_image!("00900:99999:00900:09090:90009");
+/// A ghost (MicroPython's `Image.GHOST`).
+pub const GHOST: BitImage = bit_image!("99999:90909:99999:99999:90909");
+/// A sword (MicroPython's `Image.SWORD`).
+pub const SWORD: BitImage = bit_image!("00900:00900:00900:09990:00900");
+/// A skull (MicroPython's `Image.SKULL`).
+pub const SKULL: BitImage = bit_image!("09990:90909:99999:09990:09990");
+/// An umbrella (MicroPython's `Image.UMBRELLA`).
+pub const UMBRELLA: BitImage = bit_image!("09990:99999:00900:90900:09900");
+/// A pair of scissors (MicroPython's `Image.SCISSORS`).
+pub const SCISSORS: BitImage = bit_image!("99009:99090:00900:99090:99009");
+
+/// The twelve clock hands, from [`CLOCK12`] round to [`CLOCK11`].
+pub const ALL_CLOCKS: &[BitImage] = &[
+    CLOCK12, CLOCK1, CLOCK2, CLOCK3, CLOCK4, CLOCK5,
+    CLOCK6, CLOCK7, CLOCK8, CLOCK9, CLOCK10, CLOCK11,
+];
+
+/// The eight arrows, from [`ARROW_N`] clockwise round to [`ARROW_NW`].
+pub const ALL_ARROWS: &[BitImage] = &[
+    ARROW_N, ARROW_NE, ARROW_E, ARROW_SE, ARROW_S, ARROW_SW, ARROW_W, ARROW_NW,
+];
@@ -0,0 +1,190 @@
+//! Image literals, checked at compile time.
+//!
+//! The [`greyscale_image!`] and [`bit_image!`] macros make image constants
+//! from text, in either of two forms:
+//!
+//! - MicroPython-style strings, with rows of brightness digits separated by
+//!   colons: `"09090:99999:99999:09990:00900"`
+//! - ascii art, with one row per line, using the glyphs from
+//!   [`ascii_art`]: `.` for off, `0` to `9` for that brightness, and `#` for
+//!   full brightness
+//!
+//! Whitespace is ignored, as are empty rows (so a trailing colon, or blank
+//! lines at the start and end, are fine). There must be exactly five rows of
+//! five pixels.
+//!
+//! For `bit_image!`, each pixel must be off (`.` or `0`) or on (`#`, `1` or
+//! `9`).
+//!
+//! Text which doesn't meet these rules is a compile-time error.
+//!
+//! # Example
+//!
+//! ```
+//! use rmicrobit::{bit_image, greyscale_image};
+//! use rmicrobit::graphics::image::{BitImage, GreyscaleImage};
+//!
+//! const HEART: GreyscaleImage = greyscale_image!("09090:99999:99999:09990:00900");
+//!
+//! const TICK: BitImage = bit_image!("
+//!     ....#
+//!     ...#.
+//!     #.#..
+//!     .#...
+//!     .....
+//! ");
+//! ```
+//!
+//! The [`greyscale()`] and [`bits()`] functions do the same job as the
+//! macros. They're `const fn`s, so they're also checked at compile time when
+//! used to initialise a `const` or `static`, but they panic if called at run
+//! time with bad text.
+//!
+//! [`ascii_art`]: crate::graphics::ascii_art
+//! [`bit_image!`]: crate::bit_image
+//! [`greyscale_image!`]: crate::greyscale_image
+
+use tiny_led_matrix::MAX_BRIGHTNESS;
+use crate::graphics::image::{BitImage, GreyscaleImage};
+
+#[cfg(test)]
+#[path = "../../unit_tests/graphics/literal_tests.rs"]
+mod tests;
+
+const fn glyph_brightness(c: u8) -> u8 {
+    match c {
+        b'.' => 0,
+        b'#' => MAX_BRIGHTNESS,
+        b'0'..=b'9' => c - b'0',
+        _ => panic!("image literal: unexpected character"),
+    }
+}
+
+const fn parse(text: &str) -> [[u8; 5]; 5] {
+    let bytes = text.as_bytes();
+    let mut data = [[0; 5]; 5];
+    // number of complete rows, and pixels in the current row
+    let mut y = 0;
+    let mut x = 0;
+    let mut i = 0;
+    while i <= bytes.len() {
+        let c = if i == bytes.len() {b'\n'} else {bytes[i]};
+        match c {
+            b'\n' | b':' => {
+                if x != 0 {
+                    if x != 5 {panic!("image literal: row is not 5 pixels wide")}
+                    y += 1;
+                    x = 0;
+                }
+            }
+            b' ' | b'\t' | b'\r' => (),
+            _ => {
+                if y == 5 {panic!("image literal: more than 5 rows")}
+                if x == 5 {panic!("image literal: row is not 5 pixels wide")}
+                data[y][x] = glyph_brightness(c);
+                x += 1;
+            }
+        }
+        i += 1;
+    }
+    if y != 5 {panic!("image literal: fewer than 5 rows")}
+    data
+}
+
+/// Makes a `GreyscaleImage` from an image literal.
+///
+/// See the [module-level documentation](self) for the format.
+///
+/// # Panics
+///
+/// Panics if the text isn't a valid image literal.
+pub const fn greyscale(text: &str) -> GreyscaleImage {
+    GreyscaleImage::new(&parse(text))
+}
+
+/// Makes a `BitImage` from an image literal.
+///
+/// See the [module-level documentation](self) for the format.
+///
+/// # Panics
+///
+/// Panics if the text isn't a valid image literal, or if any pixel isn't
+/// off (`.` or `0`) or on (`#`, `1` or `9`).
+pub const fn bits(text: &str) -> BitImage {
+    let mut data = parse(text);
+    let mut y = 0;
+    while y < 5 {
+        let mut x = 0;
+        while x < 5 {
+            data[y][x] = match data[y][x] {
+                0 => 0,
+                1 | 9 => 1,
+                _ => panic!("image literal: BitImage pixels must be off or on"),
+            };
+            x += 1;
+        }
+        y += 1;
+    }
+    BitImage::new(&data)
+}
+
+/// Makes a [`GreyscaleImage`] from an image literal, checked at compile
+/// time.
+///
+/// See [`graphics::literal`] for the format.
+///
+/// # Examples
+///
+/// ```
+/// use rmicrobit::greyscale_image;
+/// use rmicrobit::graphics::image::GreyscaleImage;
+/// const HEART: GreyscaleImage = greyscale_image!("09090:99999:99999:09990:00900");
+/// ```
+///
+/// Only five rows of five pixels are accepted:
+///
+/// ```compile_fail
+/// use rmicrobit::greyscale_image;
+/// let image = greyscale_image!("09090:99999:99999:09990");
+/// ```
+///
+/// [`GreyscaleImage`]: crate::graphics::image::GreyscaleImage
+/// [`graphics::literal`]: crate::graphics::literal
+#[macro_export]
+macro_rules! greyscale_image {
+    ($text:expr) => {{
+        const IMAGE: $crate::graphics::image::GreyscaleImage =
+            $crate::graphics::literal::greyscale($text);
+        IMAGE
+    }};
+}
+
+/// Makes a [`BitImage`] from an image literal, checked at compile time.
+///
+/// See [`graphics::literal`] for the format.
+///
+/// # Examples
+///
+/// ```
+/// use rmicrobit::bit_image;
+/// use rmicrobit::graphics::image::BitImage;
+/// const SQUARE: BitImage = bit_image!("#####:#...#:#...#:#...#:#####");
+/// ```
+///
+/// Pixels must be off or on:
+///
+/// ```compile_fail
+/// use rmicrobit::bit_image;
+/// let image = bit_image!("00000:00000:00500:00000:00000");
+/// ```
+///
+/// [`BitImage`]: crate::graphics::image::BitImage
+/// [`graphics::literal`]: crate::graphics::literal
+#[macro_export]
+macro_rules! bit_image {
+    ($text:expr) => {{
+        const IMAGE: $crate::graphics::image::BitImage =
+            $crate::graphics::literal::bits($text);
+        IMAGE
+    }};
+}
@@ -0,0 +1,181 @@
+//! Running animations at a different rate from the timer which drives them.
+//!
+//! A [`Paced`] wraps any [`Animate`] implementation (such as a scroller or a
+//! [`Sequence`]) and advances it `steps` times for every `ticks` calls to its
+//! own [`tick()`](Animate::tick). For example, a speed of 1/4 lets a 60Hz
+//! timer drive a scroller at 15 steps per second.
+//!
+//! The speed can be changed at any time without resetting the animation.
+//!
+//! After each tick, [`advanced()`](Paced::advanced) says whether the
+//! underlying animation moved, so you only need to update the display when
+//! it did.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use rmicrobit::prelude::*;
+//! use rmicrobit::graphics::pacing::Paced;
+//! use rmicrobit::graphics::scrolling_text::ScrollingStaticText;
+//! let mut scroller = Paced::new(ScrollingStaticText::default());
+//! scroller.inner_mut().set_message("Hello, world!");
+//! scroller.set_divider(4);
+//! while !scroller.is_finished() {
+//!     // on each timer interrupt
+//!     scroller.tick();
+//!     if scroller.advanced() {
+//!         frame.set(&scroller);
+//!         display.set_frame(frame);
+//!     }
+//! }
+//! ```
+//!
+//! [`Sequence`]: crate::graphics::sequence::Sequence
+
+use tiny_led_matrix::Render;
+use crate::graphics::scrolling::Animate;
+
+#[cfg(test)]
+#[path = "../../unit_tests/graphics/pacing_tests.rs"]
+mod tests;
+
+/// An [`Animate`] adapter which runs the underlying animation at a
+/// configurable speed.
+///
+/// The speed is a ratio `steps`/`ticks`: over any `ticks` consecutive calls
+/// to `tick()`, the underlying animation is ticked `steps` times, as evenly
+/// spaced as possible. Speeds greater than 1 tick the underlying animation
+/// more than once per call.
+///
+/// `Paced` implements [`Render`] when the underlying animation does,
+/// displaying its current state.
+///
+/// [`Render`]: crate::display::Render
+#[derive(Copy, Clone, Debug)]
+pub struct Paced<A: Animate> {
+    animation: A,
+    steps: usize,
+    ticks: usize,
+    // progress towards the next step, in units of 1/ticks of a step
+    accumulator: usize,
+    advanced: bool,
+}
+
+impl<A: Animate> Paced<A> {
+
+    /// Returns a `Paced` running `animation` at full speed (one step per
+    /// tick).
+    pub fn new(animation: A) -> Paced<A> {
+        Paced {
+            animation,
+            steps: 1,
+            ticks: 1,
+            accumulator: 0,
+            advanced: false,
+        }
+    }
+
+    /// Returns the speed, as (`steps`, `ticks`).
+    pub fn speed(&self) -> (usize, usize) {
+        (self.steps, self.ticks)
+    }
+
+    /// Sets the speed to `steps` steps of the underlying animation every
+    /// `ticks` ticks.
+    ///
+    /// A speed of 0 steps stops the underlying animation.
+    ///
+    /// This doesn't reset the animation; progress towards the next step is
+    /// kept in proportion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ticks` is 0.
+    pub fn set_speed(&mut self, steps: usize, ticks: usize) {
+        assert!(ticks != 0, "ticks must be nonzero");
+        self.accumulator = self.accumulator * ticks / self.ticks;
+        self.steps = steps;
+        self.ticks = ticks;
+    }
+
+    /// Sets the speed to one step of the underlying animation every
+    /// `divider` ticks.
+    ///
+    /// This is equivalent to `set_speed(1, divider)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divider` is 0.
+    pub fn set_divider(&mut self, divider: usize) {
+        self.set_speed(1, divider);
+    }
+
+    /// Says whether the most recent `tick()` advanced the underlying
+    /// animation.
+    ///
+    /// This is true if the underlying animation's own `tick()` was called at
+    /// least once. `Paced` only sees the [`Animate`] interface, so it can't
+    /// tell whether that tick changed what's displayed: for example, a
+    /// paused [`Sequence`] ignores ticks, but still counts as advanced.
+    ///
+    /// This is false after a `reset()`, and when the underlying animation
+    /// had already finished.
+    ///
+    /// [`Sequence`]: crate::graphics::sequence::Sequence
+    pub fn advanced(&self) -> bool {
+        self.advanced
+    }
+
+    /// Returns a reference to the underlying animation.
+    pub fn inner(&self) -> &A {
+        &self.animation
+    }
+
+    /// Returns a mutable reference to the underlying animation.
+    pub fn inner_mut(&mut self) -> &mut A {
+        &mut self.animation
+    }
+
+    /// Returns the underlying animation.
+    pub fn into_inner(self) -> A {
+        self.animation
+    }
+
+}
+
+impl<A: Animate> Animate for Paced<A> {
+
+    fn is_finished(&self) -> bool {
+        self.animation.is_finished()
+    }
+
+    /// Resets the underlying animation to the beginning.
+    ///
+    /// The speed is unchanged.
+    fn reset(&mut self) {
+        self.animation.reset();
+        self.accumulator = 0;
+        self.advanced = false;
+    }
+
+    fn tick(&mut self) {
+        self.advanced = false;
+        if self.animation.is_finished() {return}
+        self.accumulator += self.steps;
+        while self.accumulator >= self.ticks {
+            if self.animation.is_finished() {break}
+            self.accumulator -= self.ticks;
+            self.animation.tick();
+            self.advanced = true;
+        }
+    }
+
+}
+
+impl<A: Animate + Render> Render for Paced<A> {
+
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        self.animation.brightness_at(x, y)
+    }
+
+}
@@ -1,4 +1,4 @@
-//! Support for scrolling sequences of 5×5 images horizontally.
+//! Support for scrolling sequences of 5×5 images.
 //!
 //! Each kind of scrolling sequence is represented by a type implementing
 //! [`Animate`] (for controlling the sequence) and [`Render`] (for displaying
@@ -12,6 +12,45 @@
 //!
 //! See [`scrolling_text`] for scrolling text strings.
 //!
+//! # Direction
+//!
+//! By default, images scroll from right to left. Use
+//! [`ScrollingState::set_direction()`] (via [`Scrollable::state_mut()`]) to
+//! scroll in a different [`Direction`]:
+//!
+//! ```ignore
+//! scroller.state_mut().set_direction(Direction::Up);
+//! ```
+//!
+//! # Spacing
+//!
+//! The `ScrollingState` also controls the number of blank columns before,
+//! between, and after the images; see [Layout](ScrollingState#layout).
+//!
+//! In [proportional mode](ScrollingState#proportional-mode), blank columns
+//! at the sides of each image are skipped.
+//!
+//! # Smooth scrolling
+//!
+//! By default the images move one column per tick. With
+//! [`ScrollingState::set_substeps()`], each column takes several ticks, and
+//! the intermediate positions are displayed by splitting each pixel's
+//! brightness between adjacent LEDs:
+//!
+//! ```ignore
+//! scroller.state_mut().set_substeps(4);
+//! ```
+//!
+//! # Marquee mode
+//!
+//! In [marquee mode](ScrollingState#marquee-mode), the first image follows
+//! the last one again after a gap, so the sequence loops seamlessly (either
+//! indefinitely or a given number of times):
+//!
+//! ```ignore
+//! scroller.state_mut().set_marquee(5, None);
+//! ```
+//!
 //! # Example
 //!
 //! ```ignore
@@ -44,6 +83,11 @@
 //! [`scrolling_text`]: crate::graphics::scrolling_text
 
 use tiny_led_matrix::Render;
+use crate::graphics::compose::blend;
+
+#[cfg(test)]
+#[path = "../../unit_tests/graphics/scrolling_tests.rs"]
+mod tests;
 
 
 /// The state of an animation.
@@ -63,40 +107,348 @@ pub trait Animate {
 }
 
 
+/// The direction in which a scrolling sequence moves.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Direction {
+    /// Images enter from the right and move to the left.
+    Left,
+    /// Images enter from the left and move to the right.
+    Right,
+    /// Images enter from the bottom and move up.
+    Up,
+    /// Images enter from the top and move down.
+    Down,
+}
+
+impl Default for Direction {
+
+    fn default() -> Direction {
+        Direction::Left
+    }
+
+}
+
+impl Direction {
+
+    // Returns the (u, v) coordinates of display position (x, y), where u is
+    // the distance along the direction of scrolling (counting from the
+    // edge where images leave) and v is the distance across it.
+    pub(crate) fn strip_coordinates(self, x: usize, y: usize) -> (usize, usize) {
+        match self {
+            Direction::Left => (x, y),
+            Direction::Right => (4-x, y),
+            Direction::Up => (y, x),
+            Direction::Down => (4-y, x),
+        }
+    }
+
+    // The inverse of strip_coordinates().
+    fn image_coordinates(self, u: usize, v: usize) -> (usize, usize) {
+        match self {
+            Direction::Left => (u, v),
+            Direction::Right => (4-u, v),
+            Direction::Up => (v, u),
+            Direction::Down => (v, 4-u),
+        }
+    }
+
+}
+
+
+/// The width given to blank images in proportional mode.
+///
+/// See [Proportional mode](ScrollingState#proportional-mode).
+pub const PROPORTIONAL_BLANK_WIDTH: usize = 2;
+
+
 /// Data needed to record the state of a scrolling animation.
 ///
 /// Implementations of [`Scrollable`] should contain one of these and make it
 /// available via `state()` and `state_mut()`.
-#[derive(Default)]
+///
+/// As well as the current point in the animation, this records the
+/// direction of scrolling and the layout of the scrolling sequence.
+///
+/// # Layout
+///
+/// The images are laid out in a *strip*, which moves past the display one
+/// column (or row) per tick. The strip is made up of:
+///
+/// - a *lead-in* of blank columns;
+/// - the images, separated by a *gap* of blank columns;
+/// - a *lead-out* of blank columns.
+///
+/// At the start of the animation, the display shows the first five columns
+/// of the strip. The animation is finished when it shows the last five.
+///
+/// By default the lead-in and lead-out are both 5 columns, and the gap is
+/// 0, so the display is blank at the start and end of the animation and
+/// adjacent images touch.
+///
+/// # Proportional mode
+///
+/// By default, each image takes up 5 columns of the strip.
+///
+/// In proportional mode, blank columns at either side of each image are
+/// left out of the strip (see [`Scrollable::subimage_extent()`]). Images
+/// which are entirely blank take up [`PROPORTIONAL_BLANK_WIDTH`] columns.
+///
+/// # Smooth scrolling
+///
+/// The strip normally moves one column per tick. If the number of
+/// *substeps* is greater than 1, each column takes that many ticks instead.
+/// Between whole-column positions, each LED displays a weighted average of
+/// the two strip columns it lies between.
+///
+/// # Marquee mode
+///
+/// In marquee mode, the images are repeated in the strip: after the last
+/// image of each *pass* there's a *marquee gap*, then the first image of the
+/// next pass. The lead-in comes before only the first pass, and the
+/// lead-out after only the last pass.
+///
+/// The number of passes can be limited, or unlimited (so the animation never
+/// finishes). Whichever is chosen, the offset stays small: after each pass,
+/// the state moves back to the equivalent position in the first pass.
+///
+/// (When scrolling up or down, 'columns' here means rows.)
 #[derive(Copy, Clone, Debug)]
 pub struct ScrollingState {
-    // index of the character being scrolled on, or about to be scrolled on
-    index: usize,
-    // 0..5
-    pixel: usize,
+    // number of columns the strip has moved since the start
+    offset: usize,
+    // ticks per column, and ticks since the strip last moved a whole column
+    substeps: usize,
+    phase: usize,
+    direction: Direction,
+    lead_in: usize,
+    gap: usize,
+    lead_out: usize,
+    proportional: bool,
+    // Some(gap) in marquee mode
+    marquee_gap: Option<usize>,
+    // maximum number of passes in marquee mode (None for unlimited)
+    repeats: Option<usize>,
+    // number of complete passes in marquee mode
+    passes: usize,
+    // The index of an image, the position in the strip of its first column,
+    // and its position as given by Scrollable::next_position(). All images
+    // before this one are entirely before `offset`. If there are any images,
+    // this is never past the last one.
+    cursor_index: usize,
+    cursor_start: usize,
+    cursor_position: (usize, usize),
 }
 
 impl ScrollingState {
 
+    /// Returns a new `ScrollingState` with the default settings, at the
+    /// start of the animation.
+    pub const fn new() -> ScrollingState {
+        ScrollingState {
+            offset: 0,
+            substeps: 1,
+            phase: 0,
+            direction: Direction::Left,
+            lead_in: 5,
+            gap: 0,
+            lead_out: 5,
+            proportional: false,
+            marquee_gap: None,
+            repeats: None,
+            passes: 0,
+            cursor_index: 0,
+            cursor_start: 5,
+            cursor_position: (0, 0),
+        }
+    }
+
+    /// Returns the direction of scrolling.
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Sets the direction of scrolling.
+    ///
+    /// This doesn't reset the animation.
+    pub fn set_direction(&mut self, direction: Direction) {
+        self.direction = direction;
+        self.reset_cursor();
+    }
+
+    /// Returns the number of blank columns before the first image.
+    pub fn lead_in(&self) -> usize {
+        self.lead_in
+    }
+
+    /// Sets the number of blank columns before the first image.
+    ///
+    /// This doesn't reset the animation.
+    pub fn set_lead_in(&mut self, columns: usize) {
+        self.lead_in = columns;
+        self.reset_cursor();
+    }
+
+    /// Returns the number of blank columns between adjacent images.
+    pub fn gap(&self) -> usize {
+        self.gap
+    }
+
+    /// Sets the number of blank columns between adjacent images.
+    ///
+    /// This doesn't reset the animation.
+    pub fn set_gap(&mut self, columns: usize) {
+        self.gap = columns;
+        self.reset_cursor();
+    }
+
+    /// Returns the number of blank columns after the last image.
+    pub fn lead_out(&self) -> usize {
+        self.lead_out
+    }
+
+    /// Sets the number of blank columns after the last image.
+    ///
+    /// If this is less than 5, the animation finishes with (part of) the
+    /// last image still displayed.
+    ///
+    /// This doesn't reset the animation.
+    pub fn set_lead_out(&mut self, columns: usize) {
+        self.lead_out = columns;
+    }
+
+    /// Says whether proportional mode is enabled.
+    pub fn is_proportional(&self) -> bool {
+        self.proportional
+    }
+
+    /// Enables or disables proportional mode.
+    ///
+    /// See [Proportional mode](ScrollingState#proportional-mode).
+    ///
+    /// This doesn't reset the animation.
+    pub fn set_proportional(&mut self, proportional: bool) {
+        self.proportional = proportional;
+        self.reset_cursor();
+    }
+
+    /// Returns the number of ticks the strip takes to move one column.
+    pub fn substeps(&self) -> usize {
+        self.substeps
+    }
+
+    /// Sets the number of ticks the strip takes to move one column.
+    ///
+    /// Values greater than 1 enable smooth scrolling (see [Smooth
+    /// scrolling](ScrollingState#smooth-scrolling)). 0 is treated as 1.
+    ///
+    /// This doesn't reset the animation; progress towards the next column is
+    /// kept in proportion.
+    pub fn set_substeps(&mut self, substeps: usize) {
+        let substeps = substeps.max(1);
+        self.phase = self.phase * substeps / self.substeps;
+        self.substeps = substeps;
+    }
+
+    /// Returns the marquee gap, or `None` if marquee mode is disabled.
+    pub fn marquee_gap(&self) -> Option<usize> {
+        self.marquee_gap
+    }
+
+    /// Returns the maximum number of passes in marquee mode, or `None` if
+    /// it's unlimited.
+    pub fn repeats(&self) -> Option<usize> {
+        self.repeats
+    }
+
+    /// Returns the number of complete passes so far in marquee mode.
+    pub fn passes(&self) -> usize {
+        self.passes
+    }
+
+    /// Enables marquee mode, with `gap` blank columns between the last
+    /// image and the next pass's first image.
+    ///
+    /// `repeats` is the maximum number of passes (`Some(1)` is the same as
+    /// not using marquee mode), or `None` to repeat indefinitely.
+    ///
+    /// See [Marquee mode](ScrollingState#marquee-mode).
+    ///
+    /// This doesn't reset the animation.
+    pub fn set_marquee(&mut self, gap: usize, repeats: Option<usize>) {
+        self.marquee_gap = Some(gap);
+        self.repeats = repeats.map(|repeats| repeats.max(1));
+    }
+
+    /// Disables marquee mode.
+    ///
+    /// The animation finishes at the end of the current pass.
+    ///
+    /// This doesn't reset the animation.
+    pub fn clear_marquee(&mut self) {
+        self.marquee_gap = None;
+        self.repeats = None;
+    }
+
+    /// Returns the index of the *cursor* image.
+    ///
+    /// No image before the cursor is displayed (until the next marquee
+    /// pass), so it's a good starting point for finding the images which
+    /// are.
+    pub fn cursor_index(&self) -> usize {
+        self.cursor_index
+    }
+
+    /// Returns the position of the cursor image, as calculated by
+    /// [`Scrollable::next_position()`].
+    pub fn cursor_position(&self) -> (usize, usize) {
+        self.cursor_position
+    }
+
     /// Reset the state to the beginning.
     pub fn reset(&mut self) {
-        self.index = 0;
-        self.pixel = 0;
+        self.offset = 0;
+        self.phase = 0;
+        self.passes = 0;
+        self.reset_cursor();
     }
 
     /// Advance the state by one tick.
     pub fn tick(&mut self) {
-        self.pixel += 1;
-        if self.pixel == 5 {
-            self.pixel = 0;
-            self.index += 1;
+        self.phase += 1;
+        if self.phase >= self.substeps {
+            self.phase = 0;
+            self.offset += 1;
         }
     }
 
+    fn reset_cursor(&mut self) {
+        self.cursor_index = 0;
+        self.cursor_start = self.lead_in;
+        self.cursor_position = (0, 0);
+    }
+
+    // Returns the marquee gap, if the strip has another pass after the
+    // pass which is `ahead` passes after the current one.
+    fn gap_before_pass(&self, ahead: usize) -> Option<usize> {
+        let gap = self.marquee_gap?;
+        match self.repeats {
+            Some(repeats) if self.passes + ahead + 1 >= repeats => None,
+            _ => Some(gap),
+        }
+    }
+
+}
+
+impl Default for ScrollingState {
+
+    fn default() -> ScrollingState {
+        ScrollingState::new()
+    }
+
 }
 
 
-/// A horizontally scrolling sequence of 5×5 images.
+/// A scrolling sequence of 5×5 images.
 ///
 /// `Scrollable`s automatically implement [`Animate`].
 ///
@@ -105,7 +457,10 @@ impl ScrollingState {
 pub trait Scrollable {
 
     /// The type of the underlying 5×5 images.
-    type Subimage: Render;
+    ///
+    /// This may be unsized (for example `dyn Render`, for a sequence of
+    /// images of different types).
+    type Subimage: Render + ?Sized;
 
     /// The number of underlying images.
     fn length(&self) -> usize;
@@ -120,20 +475,143 @@ pub trait Scrollable {
     /// A reference to the underlying image at the specified index.
     fn subimage(&self, index: usize) -> &Self::Subimage;
 
+    /// Returns the first column and number of columns of the underlying
+    /// image at the specified index which appear in the scrolling strip.
+    ///
+    /// Unless the [`ScrollingState`] is in proportional mode, this is
+    /// `(0, 5)`.
+    ///
+    /// In proportional mode, the default implementation examines the image
+    /// to find its blank columns (or rows, when scrolling up or down). You
+    /// can override it if there's a faster way to find out.
+    fn subimage_extent(&self, index: usize) -> (usize, usize) {
+        if !self.state().proportional {return (0, 5)}
+        scanned_extent(self.subimage(index), self.state().direction)
+    }
+
+    /// Returns the position of the underlying image after the one with the
+    /// specified position.
+    ///
+    /// A position is a pair of numbers whose meaning is up to the
+    /// `Scrollable`; the image at index 0 has position (0, 0). The
+    /// [`ScrollingState`] keeps track of the position of its cursor image
+    /// (see [`ScrollingState::cursor_position()`]), so a `Scrollable` whose
+    /// images are slow to find by index can start looking from there. For
+    /// example, the text scrollers use each character's byte offset in the
+    /// message.
+    ///
+    /// The default implementation always returns (0, 0).
+    fn next_position(&self, _position: (usize, usize)) -> (usize, usize) {
+        (0, 0)
+    }
+
     /// Returns the brightness value for a single LED in the current state.
     ///
     /// Use this to implement `Render`.
     fn current_brightness_at(&self, x: usize, y: usize) -> u8 {
-        if self.state().index > self.length() {return 0}
         let state = self.state();
-        let (index, x) = if x + state.pixel < 5 {
-            if state.index == 0 {return 0}
-            (state.index - 1, x + state.pixel)
-        } else {
-            if state.index == self.length() {return 0}
-            (state.index, x + state.pixel - 5)
+        let (u, v) = state.direction.strip_coordinates(x, y);
+        let brightness = |s| match locate(self, s) {
+            Some((index, u)) => {
+                let (x, y) = state.direction.image_coordinates(u, v);
+                self.subimage(index).brightness_at(x, y)
+            }
+            None => 0,
         };
-        self.subimage(index).brightness_at(x, y)
+        let here = brightness(state.offset + u);
+        if state.phase == 0 {return here}
+        let alpha = state.phase * 255 / state.substeps;
+        blend(here, brightness(state.offset + u + 1), alpha as u8)
+    }
+}
+
+// Returns the first column and number of columns of `image` which aren't
+// blank, measured along the direction of scrolling.
+pub(crate) fn scanned_extent<R: Render + ?Sized>(image: &R, direction: Direction)
+-> (usize, usize) {
+    let is_blank = |u| (0..5).all(|v| {
+        let (x, y) = direction.image_coordinates(u, v);
+        image.brightness_at(x, y) == 0
+    });
+    match (0..5).position(|u| !is_blank(u)) {
+        Some(first) => {
+            let last = (0..5).rposition(|u| !is_blank(u)).unwrap();
+            (first, last + 1 - first)
+        }
+        None => (0, PROPORTIONAL_BLANK_WIDTH),
+    }
+}
+
+// Returns the image index and column within that image at position `s` in
+// the scrolling strip, or None if that position is blank.
+//
+// `s` must not be before the state's offset.
+fn locate<T: Scrollable + ?Sized>(scrollable: &T, s: usize) -> Option<(usize, usize)> {
+    let state = scrollable.state();
+    let mut index = state.cursor_index;
+    let mut start = state.cursor_start;
+    let mut pass = 0;
+    if scrollable.length() == 0 {return None}
+    loop {
+        let pass_start = start;
+        while index < scrollable.length() && s >= start {
+            let (first, width) = scrollable.subimage_extent(index);
+            if s < start + width {return Some((index, first + s - start))}
+            start += width + state.gap;
+            index += 1;
+        }
+        if index < scrollable.length() {return None}
+        // s is after the last image; look in the next marquee pass
+        let next_start = start - state.gap + state.gap_before_pass(pass)?;
+        if s < next_start || (pass > 0 && next_start == pass_start) {return None}
+        index = 0;
+        start = next_start;
+        pass += 1;
+    }
+}
+
+// In marquee mode, once the next pass has reached the point where the
+// current pass started, moves back to the equivalent point in the current
+// pass.
+fn wrap_marquee<T: Scrollable + ?Sized>(scrollable: &mut T) {
+    let state = scrollable.state();
+    if scrollable.length() == 0 {return}
+    let last = scrollable.length() - 1;
+    let gap = match state.gap_before_pass(0) {
+        Some(gap) if state.cursor_index == last => gap,
+        _ => return,
+    };
+    let next_start = state.cursor_start + scrollable.subimage_extent(last).1 + gap;
+    let period = next_start - state.lead_in;
+    if period == 0 || state.offset < next_start {return}
+    let state = scrollable.state_mut();
+    state.offset -= period;
+    state.passes += 1;
+    recalculate_cursor(scrollable);
+}
+
+// Moves the state's cursor back to the first image, then forward past any
+// images which have scrolled off.
+//
+// Use this if the images may have changed without resetting the animation.
+pub(crate) fn recalculate_cursor<T: Scrollable + ?Sized>(scrollable: &mut T) {
+    scrollable.state_mut().reset_cursor();
+    advance_cursor(scrollable);
+}
+
+// Moves the state's cursor forward past any images which have scrolled off.
+fn advance_cursor<T: Scrollable + ?Sized>(scrollable: &mut T) {
+    loop {
+        let state = scrollable.state();
+        if state.cursor_index + 1 >= scrollable.length() {return}
+        let (_, width) = scrollable.subimage_extent(state.cursor_index);
+        let end = state.cursor_start + width;
+        if end > state.offset {return}
+        let position = scrollable.next_position(state.cursor_position);
+        let state = scrollable.state_mut();
+        state.cursor_index += 1;
+        state.cursor_start = end + state.gap;
+        state.cursor_position = position;
     }
 }
 
@@ -141,7 +619,21 @@ pub trait Scrollable {
 impl<T : Scrollable> Animate for T {
 
     fn is_finished(&self) -> bool {
-        self.state().index > self.length()
+        let state = self.state();
+        if self.length() != 0 && state.gap_before_pass(0).is_some() {return false}
+        let limit = state.offset + 5;
+        let mut index = state.cursor_index;
+        let mut end = state.cursor_start;
+        if self.length() != 0 {
+            loop {
+                end += self.subimage_extent(index).1;
+                if index + 1 == self.length() {break}
+                end += state.gap;
+                if end > limit {return false}
+                index += 1;
+            }
+        }
+        limit >= end + state.lead_out
     }
 
     fn reset(&mut self) {
@@ -151,6 +643,8 @@ impl<T : Scrollable> Animate for T {
     fn tick(&mut self) {
         if !self.is_finished() {
             self.state_mut().tick();
+            advance_cursor(self);
+            wrap_marquee(self);
         }
     }
 }
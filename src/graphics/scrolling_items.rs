@@ -0,0 +1,225 @@
+//! Scrolling a mixture of text and images.
+//!
+//! A [`ScrollingItems`] scrolls a slice of [`Item`]s, each of which is a run
+//! of text, a [`BitImage`], a [`GreyscaleImage`], or some blank columns.
+//! Text is displayed in the same way as by the
+//! [`scrolling_text`](crate::graphics::scrolling_text) scrollers, including
+//! proportional spacing.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use rmicrobit::prelude::*;
+//! use rmicrobit::graphics::scrolling_items::{Item, ScrollingItems};
+//! const TROPHY: BitImage = bit_image!("#####:#####:.###.:..#..:.###.");
+//! let items = [
+//!     Item::Text("Score"),
+//!     Item::Spacer(2),
+//!     Item::Bits(&TROPHY),
+//!     Item::Spacer(2),
+//!     Item::Text("42"),
+//! ];
+//! let mut scroller = ScrollingItems::default();
+//! scroller.set_items(&items);
+//! scroller.set_proportional(true);
+//! while !scroller.is_finished() {
+//!     // every 50ms or so
+//!     scroller.tick();
+//!     frame.set(&scroller);
+//!     display.set_frame(frame);
+//! }
+//! ```
+//!
+//! [`BitImage`]: crate::graphics::image::BitImage
+//! [`GreyscaleImage`]: crate::graphics::image::GreyscaleImage
+
+use tiny_led_matrix::Render;
+
+#[cfg(test)]
+#[path = "../../unit_tests/graphics/scrolling_items_tests.rs"]
+mod tests;
+
+use crate::graphics::font::{Font, Pendolino};
+use crate::graphics::image::{BitImage, GreyscaleImage};
+use crate::graphics::scrolling::{scanned_extent, Animate, Scrollable, ScrollingState};
+use crate::graphics::scrolling_text::{char_count, decode_at, glyph_extent, set_proportional};
+
+static BLANK: BitImage = BitImage::blank();
+
+/// One item in a [`ScrollingItems`] sequence.
+#[derive(Copy, Clone, Debug)]
+pub enum Item<'a> {
+    /// A run of text, displayed one glyph per character.
+    ///
+    /// See [Text encoding](crate::graphics::scrolling_text#text-encoding).
+    Text(&'a str),
+    /// A black-and-white image.
+    Bits(&'a BitImage),
+    /// A greyscale image.
+    Greyscale(&'a GreyscaleImage),
+    /// The specified number of blank columns (at most 5).
+    ///
+    /// Like the other items, a spacer is separated from its neighbours by
+    /// the scrolling state's [gap](ScrollingState::set_gap).
+    Spacer(usize),
+}
+
+// A single underlying image of a ScrollingItems.
+enum Part<'a> {
+    Glyph(char),
+    Image(&'a (dyn Render + 'static)),
+    Spacer(usize),
+}
+
+/// A [`Scrollable`] displaying a slice of text runs, images and spacers.
+///
+/// Each character of a text run, and each other item, is one underlying
+/// image of the scrolling sequence.
+///
+/// Text is displayed using the font `F` (by default, [`Pendolino`]).
+///
+/// [`Pendolino`]: crate::graphics::font::Pendolino
+#[derive(Copy, Clone)]
+pub struct ScrollingItems<'a, F: Font = Pendolino> {
+    items: &'a [Item<'a>],
+    length: usize,
+    font: F,
+    state: ScrollingState,
+}
+
+impl<'a, F: Font> ScrollingItems<'a, F> {
+
+    /// Returns a new `ScrollingItems` which uses the specified font.
+    ///
+    /// There are initially no items.
+    pub fn with_font(font: F) -> ScrollingItems<'a, F> {
+        ScrollingItems {
+            items: &[],
+            length: 0,
+            font,
+            state: Default::default(),
+        }
+    }
+
+    /// Specifies the items to be displayed.
+    ///
+    /// This also resets the animation to the beginning.
+    pub fn set_items(&mut self, items: &'a [Item<'a>]) {
+        self.items = items;
+        self.length = items.iter().map(|item| match item {
+            Item::Text(text) => char_count(text.as_bytes()),
+            _ => 1,
+        }).sum();
+        self.reset();
+    }
+
+    /// Enables or disables proportional spacing.
+    ///
+    /// See [`ScrollingStaticText::set_proportional()`]. Images are displayed
+    /// without their blank columns, and spacers keep their width.
+    ///
+    /// This also resets the animation to the beginning.
+    ///
+    /// [`ScrollingStaticText::set_proportional()`]: crate::graphics::scrolling_text::ScrollingStaticText::set_proportional
+    pub fn set_proportional(&mut self, proportional: bool) {
+        set_proportional(&mut self.state, proportional);
+        self.reset();
+    }
+
+    // Positions (see Scrollable::next_position()) are an index into `items`
+    // and a byte offset within a text item. This moves a position past the
+    // end of a text item on to the next item.
+    fn skip_text_end(&self, (mut item, mut offset): (usize, usize)) -> (usize, usize) {
+        while let Some(Item::Text(text)) = self.items.get(item) {
+            if offset < text.len() {break}
+            item += 1;
+            offset = 0;
+        }
+        (item, offset)
+    }
+
+    // Returns the underlying image at `index`, starting from the state's
+    // cursor if it's not after `index`.
+    fn part(&self, index: usize) -> Part<'a> {
+        let (mut i, mut position) = (0, (0, 0));
+        if self.state.cursor_index() <= index {
+            i = self.state.cursor_index();
+            position = self.state.cursor_position();
+        }
+        while i < index {
+            position = self.next_position(position);
+            i += 1;
+        }
+        let (item, offset) = self.skip_text_end(position);
+        match *self.items.get(item).expect("subimage index out of range") {
+            Item::Text(text) => Part::Glyph(decode_at(text.as_bytes(), offset).0),
+            Item::Bits(image) => Part::Image(image),
+            Item::Greyscale(image) => Part::Image(image),
+            Item::Spacer(columns) => Part::Spacer(columns),
+        }
+    }
+
+}
+
+impl Default for ScrollingItems<'_> {
+
+    fn default() -> Self {
+        ScrollingItems::with_font(Pendolino)
+    }
+
+}
+
+impl<F: Font> Scrollable for ScrollingItems<'_, F> {
+
+    type Subimage = dyn Render;
+
+    fn length(&self) -> usize {
+        self.length
+    }
+
+    fn state(&self) -> &ScrollingState {
+        &self.state
+    }
+
+    fn state_mut(&mut self) -> &mut ScrollingState {
+        &mut self.state
+    }
+
+    fn subimage(&self, index: usize) -> &Self::Subimage {
+        match self.part(index) {
+            Part::Glyph(c) => self.font.character(c),
+            Part::Image(image) => image,
+            Part::Spacer(_) => &BLANK,
+        }
+    }
+
+    fn subimage_extent(&self, index: usize) -> (usize, usize) {
+        match self.part(index) {
+            Part::Glyph(c) => glyph_extent(&self.font, c, &self.state),
+            Part::Image(image) if self.state.is_proportional() => {
+                scanned_extent(image, self.state.direction())
+            }
+            Part::Image(_) => (0, 5),
+            Part::Spacer(columns) => (0, columns.min(5)),
+        }
+    }
+
+    fn next_position(&self, position: (usize, usize)) -> (usize, usize) {
+        let (item, offset) = self.skip_text_end(position);
+        match self.items.get(item) {
+            Some(Item::Text(text)) => {
+                self.skip_text_end((item, decode_at(text.as_bytes(), offset).1))
+            }
+            _ => self.skip_text_end((item + 1, 0)),
+        }
+    }
+
+}
+
+impl<F: Font> Render for ScrollingItems<'_, F> {
+
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        self.current_brightness_at(x, y)
+    }
+
+}
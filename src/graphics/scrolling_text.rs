@@ -1,4 +1,4 @@
-//! Support for scrolling ascii text horizontally.
+//! Support for scrolling text.
 //!
 //! # Examples
 //!
@@ -19,39 +19,227 @@
 //! ```
 //!
 //! See examples/scroll_text.rs for a complete example.
+//!
+//! # Text encoding
+//!
+//! Messages can be given as `&str` or as byte strings, and are decoded as
+//! UTF-8, so each character is displayed as a single glyph. Ascii byte
+//! strings work as before.
+//!
+//! Each byte which isn't part of a valid UTF-8 sequence is displayed as the
+//! font's fallback glyph, as is each character which the font doesn't cover.
+//!
+//! # Formatting
+//!
+//! [`ScrollingBufferedText`] implements [`core::fmt::Write`], so messages can
+//! be built with `write!` (for example, to display sensor readings). Text
+//! which doesn't fit in the buffer is truncated.
+//!
+//! # Fonts
+//!
+//! The scrollers use the [`Pendolino`] font by default. Use `with_font()` to
+//! create a scroller using a different [`Font`].
+//!
+//! # Proportional text
+//!
+//! By default each character takes up 5 columns. Call `set_proportional(true)`
+//! on a scroller to scroll each character using only its own width, with a
+//! single blank column between characters.
 
+use core::fmt;
 use tiny_led_matrix::Render;
 
-use crate::graphics::font;
+#[cfg(test)]
+#[path = "../../unit_tests/graphics/scrolling_text_tests.rs"]
+mod tests;
+
+use crate::graphics::font::{Font, Pendolino};
 use crate::graphics::image::BitImage;
-use crate::graphics::scrolling::{Animate, ScrollingState, Scrollable};
+use crate::graphics::scrolling::{
+    recalculate_cursor,
+    scanned_extent,
+    Animate,
+    Direction,
+    ScrollingState,
+    Scrollable,
+};
+
+// Returns the character encoded at the start of `bytes`, and the number of
+// bytes it uses.
+//
+// A byte which doesn't start a valid UTF-8 sequence is treated as a single
+// U+FFFD REPLACEMENT CHARACTER.
+fn decode(bytes: &[u8]) -> (char, usize) {
+    let length = match bytes[0] {
+        0x00..=0x7F => 1,
+        0xC2..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF4 => 4,
+        _ => return (char::REPLACEMENT_CHARACTER, 1),
+    };
+    match bytes.get(..length).map(core::str::from_utf8) {
+        Some(Ok(s)) => (s.chars().next().unwrap(), length),
+        _ => (char::REPLACEMENT_CHARACTER, 1),
+    }
+}
+
+// Iterator over the characters of a UTF-8 message (see decode()).
+struct Chars<'a>(&'a [u8]);
+
+impl Iterator for Chars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.0.is_empty() {return None}
+        let (c, length) = decode(self.0);
+        self.0 = &self.0[length..];
+        Some(c)
+    }
+}
+
+// Returns the number of characters in a UTF-8 message.
+pub(crate) fn char_count(message: &[u8]) -> usize {
+    Chars(message).count()
+}
+
+// Returns the character at byte offset `offset` in a UTF-8 message, and the
+// offset of the next character.
+pub(crate) fn decode_at(message: &[u8], offset: usize) -> (char, usize) {
+    let (c, length) = decode(&message[offset..]);
+    (c, offset + length)
+}
+
+// Returns the character at position `index` in a UTF-8 message which has
+// `count` characters.
+//
+// The state's cursor position must be the cursor character's byte offset.
+// The message is decoded from there (or from the start, if `index` is before
+// the cursor).
+fn char_at(message: &[u8], count: usize, index: usize, state: &ScrollingState) -> char {
+    if count == message.len() {
+        // all ascii (or invalid bytes)
+        let (c, _) = decode_at(message, index);
+        return c;
+    }
+    let (mut i, mut offset) = (0, 0);
+    if state.cursor_index() <= index {
+        i = state.cursor_index();
+        offset = state.cursor_position().0;
+    }
+    while i < index {
+        offset = decode_at(message, offset).1;
+        i += 1;
+    }
+    decode_at(message, offset).0
+}
+
+pub(crate) fn set_proportional(state: &mut ScrollingState, proportional: bool) {
+    state.set_proportional(proportional);
+    state.set_gap(if proportional {1} else {0});
+}
+
+// Implementation of Scrollable::subimage_extent() for the text scrollers,
+// using the font's glyph widths where they apply.
+pub(crate) fn glyph_extent<F: Font>(font: &F, c: char, state: &ScrollingState) -> (usize, usize) {
+    if !state.is_proportional() {return (0, 5)}
+    match state.direction() {
+        Direction::Left => font.glyph_extent(c),
+        Direction::Right => {
+            let (first, width) = font.glyph_extent(c);
+            (5 - (first + width).min(5), width)
+        }
+        direction => scanned_extent(font.character(c), direction),
+    }
+}
 
-/// A [`Scrollable`] displaying a static ascii byte-string slice.
-#[derive(Default)]
+/// A [`Scrollable`] displaying a static string slice.
+///
+/// The text is displayed using the font `F` (by default, [`Pendolino`]).
+///
+/// See [Text encoding](self#text-encoding).
 #[derive(Copy, Clone)]
-pub struct ScrollingStaticText {
+pub struct ScrollingStaticText<F: Font = Pendolino> {
     message: &'static [u8],
+    length: usize,
+    font: F,
     state: ScrollingState,
 }
 
-impl ScrollingStaticText {
+impl<F: Font> ScrollingStaticText<F> {
 
-    /// Specifies the ascii byte-string slice to be displayed.
+    /// Returns a new `ScrollingStaticText` which uses the specified font.
+    ///
+    /// The message is initially empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rmicrobit::graphics::font::Compact;
+    /// use rmicrobit::graphics::scrolling_text::ScrollingStaticText;
+    /// let mut scroller = ScrollingStaticText::with_font(Compact);
+    /// scroller.set_message("12:30");
+    /// ```
+    pub fn with_font(font: F) -> ScrollingStaticText<F> {
+        ScrollingStaticText {
+            message: &[],
+            length: 0,
+            font,
+            state: Default::default(),
+        }
+    }
+
+    /// Specifies the message to be displayed.
+    ///
+    /// `message` can be a `&str` or a byte string.
     ///
     /// This also resets the animation to the beginning.
-    pub fn set_message(&mut self, message: &'static [u8]) {
-        self.message = message;
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rmicrobit::graphics::scrolling_text::ScrollingStaticText;
+    /// let mut scroller = ScrollingStaticText::default();
+    /// scroller.set_message("Température : 21°");
+    /// scroller.set_message(b"Hello");
+    /// ```
+    pub fn set_message<M>(&mut self, message: &'static M)
+    where M: AsRef<[u8]> + ?Sized {
+        self.message = message.as_ref();
+        self.length = char_count(self.message);
+        self.reset();
+    }
+
+    /// Enables or disables proportional spacing.
+    ///
+    /// When enabled, each character uses only as many columns as it needs,
+    /// and characters are separated by a single blank column.
+    ///
+    /// This is equivalent to calling [`ScrollingState::set_proportional()`]
+    /// and setting the [gap](ScrollingState::set_gap) to 1 (or back to 0
+    /// when disabling).
+    ///
+    /// This also resets the animation to the beginning.
+    pub fn set_proportional(&mut self, proportional: bool) {
+        set_proportional(&mut self.state, proportional);
         self.reset();
     }
 
 }
 
-impl Scrollable for ScrollingStaticText {
+impl Default for ScrollingStaticText {
+
+    fn default() -> ScrollingStaticText {
+        ScrollingStaticText::with_font(Pendolino)
+    }
+
+}
+
+impl<F: Font> Scrollable for ScrollingStaticText<F> {
 
     type Subimage = BitImage;
 
     fn length(&self) -> usize {
-        self.message.len()
+        self.length
     }
 
     fn state(&self) -> &ScrollingState {
@@ -63,13 +251,22 @@ impl Scrollable for ScrollingStaticText {
     }
 
     fn subimage(&self, index: usize) -> &BitImage {
-        font::character(self.message[index])
+        self.font.character(char_at(self.message, self.length, index, &self.state))
+    }
+
+    fn subimage_extent(&self, index: usize) -> (usize, usize) {
+        let c = char_at(self.message, self.length, index, &self.state);
+        glyph_extent(&self.font, c, &self.state)
+    }
+
+    fn next_position(&self, (offset, _): (usize, usize)) -> (usize, usize) {
+        (decode_at(self.message, offset).1, 0)
     }
 
 }
 
 
-impl Render for ScrollingStaticText {
+impl<F: Font> Render for ScrollingStaticText<F> {
 
     fn brightness_at(&self, x: usize, y: usize) -> u8 {
         self.current_brightness_at(x, y)
@@ -78,29 +275,152 @@ impl Render for ScrollingStaticText {
 }
 
 
-/// A [`Scrollable`] displaying an ascii byte-string of up to 128 bytes.
+/// The default capacity of a [`ScrollingBufferedText`], in bytes.
+pub const DEFAULT_CAPACITY: usize = 128;
+
+/// An error from setting or appending to the message of a
+/// [`ScrollingBufferedText`]: the message would be too long for its buffer.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct MessageTooLong {
+    /// The length the message would have had, in bytes.
+    pub length: usize,
+    /// The capacity of the buffer, in bytes.
+    pub capacity: usize,
+}
+
+impl fmt::Display for MessageTooLong {
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "message of {} bytes doesn't fit in {}-byte buffer",
+               self.length, self.capacity)
+    }
+
+}
+
+
+/// A [`Scrollable`] displaying a string of up to `N` bytes.
+///
+/// The text is displayed using the font `F` (by default, [`Pendolino`]).
+///
+/// The message is stored in an `N`-byte buffer within the scroller. By
+/// default `N` is [`DEFAULT_CAPACITY`] (128); choose a smaller capacity to
+/// save RAM, or a larger one for long messages:
+///
+/// ```ignore
+/// let mut scroller: ScrollingBufferedText<Pendolino, 32> =
+///     ScrollingBufferedText::with_font(Pendolino);
+/// ```
+///
+/// `Default` is implemented only for the default font and capacity.
+///
+/// See [Text encoding](self#text-encoding).
 #[derive(Copy, Clone)]
-pub struct ScrollingBufferedText {
+pub struct ScrollingBufferedText<F: Font = Pendolino, const N: usize = DEFAULT_CAPACITY> {
+    byte_length: usize,
     length: usize,
-    message: [u8; 128],
+    message: [u8; N],
+    font: F,
     state: ScrollingState,
 }
 
-impl ScrollingBufferedText {
+impl<F: Font, const N: usize> ScrollingBufferedText<F, N> {
 
-    /// Specifies the ascii byte-string to be displayed.
+    /// Returns a new `ScrollingBufferedText` which uses the specified font.
     ///
-    /// Makes a copy of the byte-string.
+    /// The message is initially empty.
+    pub fn with_font(font: F) -> ScrollingBufferedText<F, N> {
+        ScrollingBufferedText {
+            byte_length: 0,
+            length: 0,
+            message: [0; N],
+            font,
+            state: Default::default(),
+        }
+    }
+
+    /// Returns the capacity of the message buffer, in bytes.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Specifies the message to be displayed.
+    ///
+    /// `message` can be a `&str` or a byte string. Makes a copy of it.
     ///
     /// This also resets the animation to the beginning.
     ///
     /// # Panics
     ///
-    /// Panics if `message` is more than 128 bytes long.
-    pub fn set_message(&mut self, message: &[u8]) {
-        assert!(message.len() <= 128, "message too long");
-        self.length = message.len();
-        self.message[..self.length].copy_from_slice(message);
+    /// Panics if `message` is more than `N` bytes long. See
+    /// [`try_set_message()`](ScrollingBufferedText::try_set_message) for a
+    /// version which doesn't panic.
+    pub fn set_message<M: AsRef<[u8]> + ?Sized>(&mut self, message: &M) {
+        if self.try_set_message(message).is_err() {
+            panic!("message too long");
+        }
+    }
+
+    /// Specifies the message to be displayed, if it fits.
+    ///
+    /// `message` can be a `&str` or a byte string. Makes a copy of it.
+    ///
+    /// This also resets the animation to the beginning.
+    ///
+    /// Returns an error, leaving the message and the animation unchanged, if
+    /// `message` is more than `N` bytes long.
+    pub fn try_set_message<M: AsRef<[u8]> + ?Sized>(&mut self, message: &M)
+    -> Result<(), MessageTooLong> {
+        let message = message.as_ref();
+        if message.len() > N {
+            return Err(MessageTooLong {length: message.len(), capacity: N});
+        }
+        self.byte_length = message.len();
+        self.message[..self.byte_length].copy_from_slice(message);
+        self.length = char_count(message);
+        self.reset();
+        Ok(())
+    }
+
+    /// Adds text to the end of the message, if it fits.
+    ///
+    /// `text` can be a `&str` or a byte string.
+    ///
+    /// This doesn't reset the animation.
+    ///
+    /// Returns an error, leaving the message unchanged, if the result would
+    /// be more than `N` bytes long. (The [`fmt::Write`] implementation
+    /// appends as much as fits instead.)
+    ///
+    /// [`fmt::Write`]: core::fmt::Write
+    pub fn append<M: AsRef<[u8]> + ?Sized>(&mut self, text: &M)
+    -> Result<(), MessageTooLong> {
+        let text = text.as_ref();
+        let length = self.byte_length + text.len();
+        if length > N {
+            return Err(MessageTooLong {length, capacity: N});
+        }
+        self.message[self.byte_length..length].copy_from_slice(text);
+        self.byte_length = length;
+        self.length = char_count(&self.message[..length]);
+        // the new text may complete a character at the end of the old message
+        recalculate_cursor(self);
+        Ok(())
+    }
+
+    /// Clears the message.
+    ///
+    /// This also resets the animation to the beginning.
+    pub fn clear_message(&mut self) {
+        self.set_message("");
+    }
+
+    /// Enables or disables proportional spacing.
+    ///
+    /// See [`ScrollingStaticText::set_proportional()`].
+    ///
+    /// This also resets the animation to the beginning.
+    pub fn set_proportional(&mut self, proportional: bool) {
+        set_proportional(&mut self.state, proportional);
         self.reset();
     }
 
@@ -110,16 +430,12 @@ impl ScrollingBufferedText {
 impl Default for ScrollingBufferedText {
 
     fn default() -> ScrollingBufferedText {
-        ScrollingBufferedText {
-            length: 0,
-            message: [0; 128],
-            state: Default::default(),
-        }
+        ScrollingBufferedText::with_font(Pendolino)
     }
 
 }
 
-impl Scrollable for ScrollingBufferedText {
+impl<F: Font, const N: usize> Scrollable for ScrollingBufferedText<F, N> {
 
     type Subimage = BitImage;
 
@@ -136,12 +452,23 @@ impl Scrollable for ScrollingBufferedText {
     }
 
     fn subimage(&self, index: usize) -> &BitImage {
-        font::character(self.message[index])
+        let message = &self.message[..self.byte_length];
+        self.font.character(char_at(message, self.length, index, &self.state))
+    }
+
+    fn subimage_extent(&self, index: usize) -> (usize, usize) {
+        let message = &self.message[..self.byte_length];
+        let c = char_at(message, self.length, index, &self.state);
+        glyph_extent(&self.font, c, &self.state)
+    }
+
+    fn next_position(&self, (offset, _): (usize, usize)) -> (usize, usize) {
+        (decode_at(&self.message[..self.byte_length], offset).1, 0)
     }
 
 }
 
-impl Render for ScrollingBufferedText {
+impl<F: Font, const N: usize> Render for ScrollingBufferedText<F, N> {
 
     fn brightness_at(&self, x: usize, y: usize) -> u8 {
         self.current_brightness_at(x, y)
@@ -149,3 +476,34 @@ impl Render for ScrollingBufferedText {
 
 }
 
+/// Appends formatted text to the message, so that `write!` can be used to
+/// build a message.
+///
+/// Writing doesn't reset the animation.
+///
+/// If the text doesn't fit in the buffer, as much as fits is appended
+/// (stopping at a character boundary) and `write_str()` returns an error.
+/// The truncated message can still be displayed.
+///
+/// # Example
+///
+/// ```ignore
+/// use core::fmt::Write;
+/// scroller.clear_message();
+/// write!(scroller, "T={}C", temperature).ok();
+/// ```
+impl<F: Font, const N: usize> fmt::Write for ScrollingBufferedText<F, N> {
+
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut end = s.len().min(self.message.len() - self.byte_length);
+        while !s.is_char_boundary(end) {end -= 1}
+        let start = self.byte_length;
+        self.byte_length += end;
+        self.message[start..self.byte_length].copy_from_slice(&s.as_bytes()[..end]);
+        self.length = char_count(&self.message[..self.byte_length]);
+        recalculate_cursor(self);
+        if end == s.len() {Ok(())} else {Err(fmt::Error)}
+    }
+
+}
+
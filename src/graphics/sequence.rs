@@ -0,0 +1,252 @@
+//! Frame-by-frame animations.
+//!
+//! A [`Sequence`] plays a static slice of *keyframes*, each of which is an
+//! image and the number of ticks to display it for. It implements
+//! [`Animate`] (for controlling the animation) and [`Render`] (for
+//! displaying it), like the scrolling types.
+//!
+//! The [`LoopMode`] says what happens after the last keyframe.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use rmicrobit::prelude::*;
+//! use rmicrobit::graphics::images::{HEART, HEART_SMALL};
+//! use rmicrobit::graphics::image::BitImage;
+//! use rmicrobit::graphics::sequence::{LoopMode, Sequence};
+//! static HEARTBEAT: [(&BitImage, usize); 2] = [(&HEART, 2), (&HEART_SMALL, 6)];
+//! let mut animation = Sequence::default();
+//! animation.set_frames(&HEARTBEAT);
+//! animation.set_mode(LoopMode::Loop);
+//! loop {
+//!     // every 50ms or so
+//!     animation.tick();
+//!     frame.set(&animation);
+//!     display.set_frame(frame);
+//! }
+//! ```
+//!
+//! [`Animate`]: crate::graphics::scrolling::Animate
+//! [`Render`]: crate::display::Render
+
+use tiny_led_matrix::Render;
+use crate::graphics::scrolling::Animate;
+
+#[cfg(test)]
+#[path = "../../unit_tests/graphics/sequence_tests.rs"]
+mod tests;
+
+/// What a [`Sequence`] does after its last keyframe.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum LoopMode {
+    /// Plays the keyframes once, finishing on the last one.
+    Once,
+    /// Starts again from the first keyframe.
+    Loop,
+    /// Plays the keyframes backwards to the first one, then forwards again,
+    /// and so on. The first and last keyframes aren't repeated at the
+    /// turning points.
+    PingPong,
+}
+
+impl Default for LoopMode {
+
+    fn default() -> LoopMode {
+        LoopMode::Once
+    }
+
+}
+
+/// An animation playing a static slice of keyframes.
+///
+/// Each keyframe is an image (any type implementing [`Render`]) and the
+/// number of ticks for which it's displayed. A duration of 0 is treated as
+/// 1.
+///
+/// Positions in the animation are measured in ticks from the start of the
+/// first keyframe; see [`position()`](Sequence::position).
+///
+/// While the animation is paused, [`tick()`](Animate::tick) does nothing.
+///
+/// [`Render`]: crate::display::Render
+#[derive(Copy, Clone)]
+pub struct Sequence<T: Render + 'static> {
+    frames: &'static [(T, usize)],
+    mode: LoopMode,
+    paused: bool,
+    // current keyframe
+    index: usize,
+    // ticks spent in the current keyframe
+    elapsed: usize,
+    // true when playing backwards in ping-pong mode
+    reversed: bool,
+}
+
+impl<T: Render + 'static> Sequence<T> {
+
+    /// Specifies the keyframes to be displayed.
+    ///
+    /// This also resets the animation to the beginning.
+    pub fn set_frames(&mut self, frames: &'static [(T, usize)]) {
+        self.frames = frames;
+        self.reset();
+    }
+
+    /// Returns the loop mode.
+    pub fn mode(&self) -> LoopMode {
+        self.mode
+    }
+
+    /// Sets the loop mode.
+    ///
+    /// This doesn't reset the animation.
+    pub fn set_mode(&mut self, mode: LoopMode) {
+        self.mode = mode;
+        if mode != LoopMode::PingPong {self.reversed = false}
+    }
+
+    /// Stops the animation at its current position.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Restarts the animation after [`pause()`](Sequence::pause).
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Says whether the animation is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Returns the index of the keyframe currently displayed.
+    pub fn keyframe(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the total duration of the keyframes, in ticks.
+    pub fn total_ticks(&self) -> usize {
+        (0..self.frames.len()).map(|index| self.duration(index)).sum()
+    }
+
+    /// Returns the current position, in ticks from the start of the first
+    /// keyframe.
+    ///
+    /// This is between 0 and `total_ticks() - 1` (or 0 if there are no
+    /// keyframes). When a ping-pong animation is playing backwards, the
+    /// position decreases.
+    pub fn position(&self) -> usize {
+        if self.frames.is_empty() {return 0}
+        let start: usize = (0..self.index).map(|index| self.duration(index)).sum();
+        if self.reversed {
+            start + self.duration(self.index) - 1 - self.elapsed
+        } else {
+            start + self.elapsed
+        }
+    }
+
+    /// Moves to the specified position, in ticks from the start of the first
+    /// keyframe, playing forwards.
+    ///
+    /// Positions after the end of the last keyframe move to the end of the
+    /// last keyframe.
+    ///
+    /// This doesn't change whether the animation is paused.
+    pub fn seek(&mut self, position: usize) {
+        self.reversed = false;
+        let mut remaining = position;
+        for index in 0..self.frames.len() {
+            let duration = self.duration(index);
+            self.index = index;
+            if remaining < duration {
+                self.elapsed = remaining;
+                return;
+            }
+            self.elapsed = duration - 1;
+            remaining -= duration;
+        }
+    }
+
+    fn duration(&self, index: usize) -> usize {
+        self.frames[index].1.max(1)
+    }
+
+    // Moves to the start of the next keyframe.
+    fn advance(&mut self) {
+        let last = self.frames.len() - 1;
+        self.elapsed = 0;
+        match self.mode {
+            LoopMode::Once => self.index += 1,
+            LoopMode::Loop => {
+                self.index = if self.index == last {0} else {self.index + 1};
+            }
+            LoopMode::PingPong => {
+                if last == 0 {return}
+                if self.reversed && self.index == 0 {self.reversed = false}
+                if !self.reversed && self.index == last {self.reversed = true}
+                if self.reversed {self.index -= 1} else {self.index += 1}
+            }
+        }
+    }
+
+}
+
+impl<T: Render + 'static> Default for Sequence<T> {
+
+    fn default() -> Sequence<T> {
+        Sequence {
+            frames: &[],
+            mode: LoopMode::Once,
+            paused: false,
+            index: 0,
+            elapsed: 0,
+            reversed: false,
+        }
+    }
+
+}
+
+impl<T: Render + 'static> Animate for Sequence<T> {
+
+    /// Says whether the animation has completed.
+    ///
+    /// Only an animation in [`LoopMode::Once`] (or with no keyframes)
+    /// completes: it's finished when it has reached the last tick of the
+    /// last keyframe.
+    fn is_finished(&self) -> bool {
+        if self.frames.is_empty() {return true}
+        self.mode == LoopMode::Once &&
+            self.index == self.frames.len() - 1 &&
+            self.elapsed + 1 >= self.duration(self.index)
+    }
+
+    /// Reset the animation to the start of the first keyframe.
+    ///
+    /// This doesn't change whether the animation is paused.
+    fn reset(&mut self) {
+        self.index = 0;
+        self.elapsed = 0;
+        self.reversed = false;
+    }
+
+    fn tick(&mut self) {
+        if self.paused || self.is_finished() {return}
+        self.elapsed += 1;
+        if self.elapsed >= self.duration(self.index) {
+            self.advance();
+        }
+    }
+
+}
+
+impl<T: Render + 'static> Render for Sequence<T> {
+
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        match self.frames.get(self.index) {
+            Some((image, _)) => image.brightness_at(x, y),
+            None => 0,
+        }
+    }
+
+}
@@ -0,0 +1,23 @@
+//! Helpers shared by the graphics unit tests.
+
+use tiny_led_matrix::Render;
+use crate::graphics::ascii_art::{parse, AsciiArt};
+use crate::graphics::scrolling::Animate;
+
+/// Asserts that `image` displays as `expected`, in the format read by
+/// [`parse()`].
+pub(crate) fn assert_image(image: &(impl Render + ?Sized), expected: &str) {
+    let expected = parse(expected).unwrap();
+    assert_eq!(AsciiArt(image).to_string(), AsciiArt(&expected).to_string());
+}
+
+/// Ticks `animation` until it finishes, returning the number of frames
+/// displayed (including the first).
+pub(crate) fn count_frames(animation: &mut impl Animate) -> usize {
+    let mut frames = 1;
+    while !animation.is_finished() {
+        animation.tick();
+        frames += 1;
+    }
+    frames
+}
@@ -0,0 +1,154 @@
+//! Rotating, flipping and shifting 5×5 images.
+//!
+//! A [`Transform`] describes one of the eight ways of rotating and/or
+//! flipping a 5×5 image.
+//!
+//! [`GreyscaleImage`] and [`BitImage`] provide `transformed()` and
+//! `shifted()` methods which return a new image.
+//!
+//! The [`Transformed`] and [`Shifted`] adapters apply the same operations to
+//! anything implementing [`Render`], without copying it. For example:
+//!
+//! ```ignore
+//! use rmicrobit::graphics::transform::{Transform, Transformed};
+//! frame.set(&Transformed::new(&scroller, Transform::Rotate90));
+//! ```
+//!
+//! [`BitImage`]: crate::graphics::image::BitImage
+//! [`GreyscaleImage`]: crate::graphics::image::GreyscaleImage
+//! [`Render`]: crate::display::Render
+
+use tiny_led_matrix::Render;
+
+/// One of the eight symmetries of a square image.
+///
+/// Rotations are clockwise.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Transform {
+    /// Leaves the image unchanged.
+    Identity,
+    /// Rotates the image by 90°.
+    Rotate90,
+    /// Rotates the image by 180°.
+    Rotate180,
+    /// Rotates the image by 270°.
+    Rotate270,
+    /// Mirrors the image left-to-right.
+    FlipHorizontal,
+    /// Mirrors the image top-to-bottom.
+    FlipVertical,
+    /// Reflects the image in the diagonal from the top-left corner.
+    Transpose,
+    /// Reflects the image in the diagonal from the top-right corner.
+    AntiTranspose,
+}
+
+impl Default for Transform {
+
+    fn default() -> Transform {
+        Transform::Identity
+    }
+
+}
+
+impl Transform {
+
+    /// Returns the coordinates in the original image of the pixel which
+    /// appears at (x, y) in the transformed image.
+    ///
+    /// `x` and `y` should be in 0..5 .
+    pub const fn source_coordinates(self, x: usize, y: usize) -> (usize, usize) {
+        match self {
+            Transform::Identity => (x, y),
+            Transform::Rotate90 => (y, 4-x),
+            Transform::Rotate180 => (4-x, 4-y),
+            Transform::Rotate270 => (4-y, x),
+            Transform::FlipHorizontal => (4-x, y),
+            Transform::FlipVertical => (x, 4-y),
+            Transform::Transpose => (y, x),
+            Transform::AntiTranspose => (4-y, 4-x),
+        }
+    }
+
+}
+
+
+/// A [`Render`] adapter which displays an image rotated or flipped.
+///
+/// [`Render`]: crate::display::Render
+#[derive(Copy, Clone)]
+pub struct Transformed<'a, T: Render + ?Sized> {
+    image: &'a T,
+    transform: Transform,
+}
+
+impl<'a, T: Render + ?Sized> Transformed<'a, T> {
+
+    /// Returns a `Transformed` which displays `image` with `transform`
+    /// applied.
+    pub fn new(image: &'a T, transform: Transform) -> Transformed<'a, T> {
+        Transformed {image, transform}
+    }
+
+}
+
+impl<T: Render + ?Sized> Render for Transformed<'_, T> {
+
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        let (x, y) = self.transform.source_coordinates(x, y);
+        self.image.brightness_at(x, y)
+    }
+
+}
+
+
+/// A [`Render`] adapter which displays an image moved horizontally and/or
+/// vertically.
+///
+/// Pixels which are moved off the display are lost; pixels which don't
+/// correspond to any in the original image have brightness `fill`.
+///
+/// [`Render`]: crate::display::Render
+#[derive(Copy, Clone)]
+pub struct Shifted<'a, T: Render + ?Sized> {
+    image: &'a T,
+    dx: isize,
+    dy: isize,
+    fill: u8,
+}
+
+impl<'a, T: Render + ?Sized> Shifted<'a, T> {
+
+    /// Returns a `Shifted` which displays `image` moved `dx` pixels to the
+    /// right and `dy` pixels down.
+    ///
+    /// Negative values move the image left or up.
+    pub fn new(image: &'a T, dx: isize, dy: isize, fill: u8) -> Shifted<'a, T> {
+        Shifted {image, dx, dy, fill}
+    }
+
+}
+
+impl<T: Render + ?Sized> Render for Shifted<'_, T> {
+
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        match shift_source(x, y, self.dx, self.dy) {
+            Some((x, y)) => self.image.brightness_at(x, y),
+            None => self.fill,
+        }
+    }
+
+}
+
+/// Returns the coordinates in the original image of the pixel which appears
+/// at (x, y) in an image shifted by (dx, dy), if there is one.
+pub(crate) const fn shift_source(x: usize, y: usize, dx: isize, dy: isize)
+-> Option<(usize, usize)> {
+    let sx = x as isize - dx;
+    let sy = y as isize - dy;
+    if sx < 0 || sx >= 5 || sy < 0 || sy >= 5 {
+        None
+    } else {
+        Some((sx as usize, sy as usize))
+    }
+}
@@ -0,0 +1,228 @@
+//! Animated transitions from one image to another.
+//!
+//! A [`Transition`] displays a change from one `Render` to another over a
+//! given number of ticks, using one of the following [`Effect`]s:
+//!
+//! Effect                 | Appearance |
+//! ---------------------- | ---------- |
+//! [`Fade`]               | the first image fades out to black, then the second fades in |
+//! [`CrossFade`]          | the first image fades into the second |
+//! [`Wipe`]               | the second image slides over the first, in the given direction |
+//! [`Dissolve`]           | the pixels change one at a time, in pseudo-random order |
+//! [`Zoom`]               | the second image grows out from the centre |
+//!
+//! Pixels part-way through changing are shown at intermediate brightness
+//! levels, so wipes, dissolves and zooms have soft edges.
+//!
+//! Like the scrolling types, `Transition` implements [`Animate`] (for
+//! controlling the animation) and [`Render`] (for displaying it).
+//!
+//! # Example
+//!
+//! ```ignore
+//! use rmicrobit::prelude::*;
+//! use rmicrobit::graphics::images::{HAPPY, SAD};
+//! use rmicrobit::graphics::scrolling::Direction;
+//! use rmicrobit::graphics::transitions::{Effect, Transition};
+//! let mut transition = Transition::new(&SAD, &HAPPY, Effect::Wipe(Direction::Left), 20);
+//! while !transition.is_finished() {
+//!     // every 20ms or so
+//!     transition.tick();
+//!     frame.set(&transition);
+//!     display.set_frame(frame);
+//! }
+//! ```
+//!
+//! [`Fade`]: Effect::Fade
+//! [`CrossFade`]: Effect::CrossFade
+//! [`Wipe`]: Effect::Wipe
+//! [`Dissolve`]: Effect::Dissolve
+//! [`Zoom`]: Effect::Zoom
+//! [`Animate`]: crate::graphics::scrolling::Animate
+//! [`Render`]: crate::display::Render
+
+use tiny_led_matrix::Render;
+use crate::graphics::compose::blend;
+use crate::graphics::scrolling::{Animate, Direction};
+
+#[cfg(test)]
+#[path = "../../unit_tests/graphics/transitions_tests.rs"]
+mod tests;
+
+/// The seed used for [`Effect::Dissolve`] by [`Transition::new()`].
+pub const DEFAULT_DISSOLVE_SEED: u32 = 0x2545_f491;
+
+/// The visual effect of a [`Transition`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Effect {
+    /// The first image fades out to black during the first half of the
+    /// transition, and the second fades in during the second half.
+    Fade,
+    /// Each pixel changes gradually from the first image's brightness to the
+    /// second's.
+    CrossFade,
+    /// The second image covers the first, starting at one edge. The
+    /// direction is the one in which the edge moves: for example, with
+    /// [`Direction::Left`] the second image enters from the right.
+    Wipe(Direction),
+    /// Pixels change from the first image to the second one at a time, in a
+    /// pseudo-random order.
+    Dissolve,
+    /// The second image appears at the centre pixel, then in the square
+    /// around it, then at the edges.
+    Zoom,
+}
+
+/// An animated change from one image to another.
+///
+/// Holds references to the two images; each can be any type implementing
+/// [`Render`] (including another `Transition`, or an animation).
+///
+/// The transition takes `duration` ticks. It starts showing only the first
+/// image, and when it has finished it shows only the second.
+///
+/// [`Render`]: crate::display::Render
+#[derive(Copy, Clone)]
+pub struct Transition<'a, A: Render + ?Sized, B: Render + ?Sized> {
+    from: &'a A,
+    to: &'a B,
+    effect: Effect,
+    duration: usize,
+    elapsed: usize,
+    // for Dissolve, the position of each pixel (indexed by y*5 + x) in the
+    // order in which they change
+    ranks: [u8; 25],
+}
+
+impl<'a, A: Render + ?Sized, B: Render + ?Sized> Transition<'a, A, B> {
+
+    /// Returns a `Transition` from `from` to `to`, using `effect`, taking
+    /// `duration` ticks.
+    ///
+    /// A duration of 0 means the transition is finished immediately.
+    pub fn new(from: &'a A, to: &'a B, effect: Effect, duration: usize)
+    -> Transition<'a, A, B> {
+        Transition {
+            from,
+            to,
+            effect,
+            duration,
+            elapsed: 0,
+            ranks: dissolve_ranks(DEFAULT_DISSOLVE_SEED),
+        }
+    }
+
+    /// Returns the effect.
+    pub fn effect(&self) -> Effect {
+        self.effect
+    }
+
+    /// Returns the duration, in ticks.
+    pub fn duration(&self) -> usize {
+        self.duration
+    }
+
+    /// Returns the number of ticks since the start of the transition.
+    pub fn elapsed(&self) -> usize {
+        self.elapsed
+    }
+
+    /// Chooses a different pseudo-random order for [`Effect::Dissolve`].
+    ///
+    /// The same seed always gives the same order.
+    pub fn set_dissolve_seed(&mut self, seed: u32) {
+        self.ranks = dissolve_ranks(seed);
+    }
+
+    // Returns the weight (0 for the first image, 255 for the second) of the
+    // index'th of `steps` equal parts of the transition. Each part goes from
+    // 0 to 255 while it's current.
+    fn weight(&self, steps: usize, index: usize) -> u8 {
+        if self.elapsed >= self.duration {return 255}
+        let progress = self.elapsed * steps * 255 / self.duration;
+        progress.saturating_sub(index * 255).min(255) as u8
+    }
+
+}
+
+impl<A: Render + ?Sized, B: Render + ?Sized> Animate for Transition<'_, A, B> {
+
+    /// Says whether the transition has completed.
+    ///
+    /// It's finished when it has been ticked `duration` times.
+    fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Reset the transition to the beginning.
+    fn reset(&mut self) {
+        self.elapsed = 0;
+    }
+
+    fn tick(&mut self) {
+        if !self.is_finished() {self.elapsed += 1}
+    }
+
+}
+
+impl<A: Render + ?Sized, B: Render + ?Sized> Render for Transition<'_, A, B> {
+
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        let from = self.from.brightness_at(x, y);
+        let to = self.to.brightness_at(x, y);
+        let weight = match self.effect {
+            Effect::Fade => {
+                let fade_in = self.weight(2, 1);
+                return if fade_in == 0 {
+                    blend(from, 0, self.weight(2, 0))
+                } else {
+                    blend(0, to, fade_in)
+                };
+            }
+            Effect::CrossFade => self.weight(1, 0),
+            Effect::Wipe(direction) => {
+                // u counts from the edge where the second image leaves
+                let (u, _) = direction.strip_coordinates(x, y);
+                self.weight(5, 4 - u)
+            }
+            Effect::Dissolve => self.weight(25, self.ranks[y*5 + x] as usize),
+            Effect::Zoom => {
+                let ring = (x as isize - 2).abs().max((y as isize - 2).abs());
+                self.weight(3, ring as usize)
+            }
+        };
+        blend(from, to, weight)
+    }
+
+}
+
+// Returns the rank of each pixel in a pseudo-random order determined by
+// `seed`, using a Fisher-Yates shuffle driven by a xorshift generator.
+const fn dissolve_ranks(seed: u32) -> [u8; 25] {
+    let mut order = [0; 25];
+    let mut i = 0;
+    while i < 25 {
+        order[i] = i as u8;
+        i += 1;
+    }
+    // xorshift's state must be nonzero
+    let mut state = if seed == 0 {DEFAULT_DISSOLVE_SEED} else {seed};
+    let mut i = 24;
+    while i > 0 {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        let j = (state % (i as u32 + 1)) as usize;
+        let tmp = order[i];
+        order[i] = order[j];
+        order[j] = tmp;
+        i -= 1;
+    }
+    let mut ranks = [0; 25];
+    let mut rank = 0;
+    while rank < 25 {
+        ranks[order[rank] as usize] = rank as u8;
+        rank += 1;
+    }
+    ranks
+}
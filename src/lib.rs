@@ -34,6 +34,9 @@
 //! ```
 //! (or substitute your development machine's native target)
 //!
+//! The `sim` cargo feature enables a simulated display (see
+//! [`display::sim`]) which other crates can use in their own host-side tests.
+//!
 //! # Re-exports
 //!
 //! The following dependencies are re-exported under
@@ -17,11 +17,13 @@
 //! [`MicrobitGpioExt`]     | `GPIO.split_by_kind()` |
 //! [`PollButton`]          | `button_a.poll_event()` |
 //! [`Animate`]             | `scrolling_images.tick()` |
+//! [`Scrollable`]          | `scrolling_images.state_mut()` |
 //!
 //! [`MicrobitGpioExt`]: crate::gpio::MicrobitGpioExt
 //! [`PollButton`]: crate::buttons::core::PollButton
 //! [`Frame`]: tiny_led_matrix::Frame
 //! [`Animate`]: crate::graphics::scrolling::Animate
+//! [`Scrollable`]: crate::graphics::scrolling::Scrollable
 
 // I'm hiding these from rustdoc to prevent it choosing some of them as the
 // main page for the traits (eg for Frame). It seems least misleading to hide
@@ -38,3 +40,6 @@ pub use crate::buttons::core::PollButton as _;
 
 #[doc(hidden)]
 pub use crate::graphics::scrolling::Animate as _;
+
+#[doc(hidden)]
+pub use crate::graphics::scrolling::Scrollable as _;
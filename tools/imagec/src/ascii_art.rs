@@ -0,0 +1,73 @@
+//! Reading text files in `rmicrobit`'s ascii-art format.
+//!
+//! This is the format used by `rmicrobit::graphics::ascii_art`: each pixel
+//! is one of the following glyphs.
+//!
+//! Glyph       | Brightness |
+//! ----------- | ---------- |
+//! `.`         | 0          |
+//! `0` to `9`  | that level |
+//! `#`         | 9          |
+//!
+//! A file can contain several images, separated by one or more blank lines.
+//! Each image has five rows; its width can be any multiple of 5, in which
+//! case it's split into 5×5 tiles (see [`split_tiles()`]). Leading and
+//! trailing whitespace on each line is ignored.
+//!
+//! [`split_tiles()`]: crate::split_tiles
+
+use crate::{split_tiles, Error, Pixels, MAX_BRIGHTNESS};
+
+#[cfg(test)]
+#[path = "../unit_tests/ascii_art_tests.rs"]
+mod tests;
+
+fn glyph_brightness(line_number: usize, c: char) -> Result<u8, Error> {
+    match c {
+        '.' => Ok(0),
+        '#' => Ok(MAX_BRIGHTNESS),
+        '0'..='9' => Ok(c as u8 - b'0'),
+        _ => Err(Error::at_line(line_number, format!("unexpected character {:?}", c))),
+    }
+}
+
+// Converts one block of lines (with their line numbers) to tiles.
+fn parse_block(block: &[(usize, &str)]) -> Result<Vec<Pixels>, Error> {
+    let (first_line, first_row) = block[0];
+    if block.len() != 5 {
+        return Err(Error::at_line(first_line, "image is not 5 rows high"));
+    }
+    let width = first_row.chars().count();
+    let mut rows = Vec::new();
+    for &(line_number, line) in block {
+        let row = line.chars()
+            .map(|c| glyph_brightness(line_number, c))
+            .collect::<Result<Vec<u8>, Error>>()?;
+        if row.len() != width {
+            return Err(Error::at_line(line_number, "rows have different lengths"));
+        }
+        rows.push(row);
+    }
+    split_tiles(width, 5, |x, y| rows[y][x])
+        .map_err(|e| Error::at_line(first_line, e.message))
+}
+
+/// Reads all the images from an ascii-art file.
+///
+/// Returns brightness values from 0 to `MAX_BRIGHTNESS`.
+pub fn parse(text: &str) -> Result<Vec<Pixels>, Error> {
+    let mut images = Vec::new();
+    let mut block = Vec::new();
+    let lines = text.lines().map(str::trim).chain(std::iter::once(""));
+    for (index, line) in lines.enumerate() {
+        if line.is_empty() {
+            if !block.is_empty() {
+                images.extend(parse_block(&block)?);
+                block.clear();
+            }
+        } else {
+            block.push((index + 1, line));
+        }
+    }
+    Ok(images)
+}
@@ -0,0 +1,137 @@
+//! Reading fonts in the Glyph Bitmap Distribution Format (BDF).
+//!
+//! Only the parts of the format needed to place each glyph in a 5×5 cell
+//! are used: the `FONTBOUNDINGBOX`, and each glyph's `ENCODING`, `BBX` and
+//! `BITMAP`. Other lines are ignored.
+//!
+//! The font bounding box's top-left corner becomes the top-left corner of
+//! the 5×5 cell. It's an error for a glyph to have a pixel set outside the
+//! cell.
+
+use crate::{Error, Pixels};
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+#[cfg(test)]
+#[path = "../unit_tests/bdf_tests.rs"]
+mod tests;
+
+/// A font read from a BDF file: 5×5 glyphs indexed by code point.
+pub type Glyphs = BTreeMap<u32, Pixels>;
+
+#[derive(Copy, Clone)]
+struct BoundingBox {
+    width: usize,
+    height: usize,
+    x_offset: isize,
+    y_offset: isize,
+}
+
+fn parse_bounding_box(line_number: usize, args: &[&str]) -> Result<BoundingBox, Error> {
+    let numbers = args.iter()
+        .map(|arg| arg.parse::<isize>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| Error::at_line(line_number, "bad bounding box"))?;
+    match numbers[..] {
+        [width, height, x_offset, y_offset] if width >= 0 && height >= 0 => {
+            Ok(BoundingBox {
+                width: width as usize,
+                height: height as usize,
+                x_offset,
+                y_offset,
+            })
+        }
+        _ => Err(Error::at_line(line_number, "bad bounding box")),
+    }
+}
+
+// Sets the pixels from one row of a glyph's BITMAP in `cell`.
+fn place_row(
+    line_number: usize,
+    hex: &str,
+    row: usize,
+    font_box: BoundingBox,
+    glyph_box: BoundingBox,
+    cell: &mut Pixels,
+) -> Result<(), Error> {
+    let bad_bitmap = || Error::at_line(line_number, "bad bitmap row");
+    if hex.len() != (glyph_box.width + 7) / 8 * 2 || hex.len() > 16 {
+        return Err(bad_bitmap());
+    }
+    let bits = u64::from_str_radix(hex, 16).map_err(|_| bad_bitmap())?;
+    let top = font_box.y_offset + font_box.height as isize;
+    let y = top - (glyph_box.y_offset + glyph_box.height as isize) + row as isize;
+    for column in 0..glyph_box.width {
+        let shift = hex.len() * 4 - 1 - column;
+        if bits & (1 << shift) == 0 {continue}
+        let x = glyph_box.x_offset - font_box.x_offset + column as isize;
+        if !(0..5).contains(&x) || !(0..5).contains(&y) {
+            return Err(Error::at_line(line_number, "glyph doesn't fit in 5×5"));
+        }
+        cell[y as usize][x as usize] = 1;
+    }
+    Ok(())
+}
+
+/// Reads a BDF font.
+///
+/// Returns the glyphs as 5×5 [`Pixels`] with values 0 and 1.
+///
+/// Glyphs with no encoding (`ENCODING -1`) are skipped.
+pub fn parse(text: &str) -> Result<Glyphs, Error> {
+    let mut glyphs = Glyphs::new();
+    let mut font_box = None;
+    let mut encoding = None;
+    let mut glyph_box = None;
+    let mut bitmap_row = None;
+    let mut cell = [[0; 5]; 5];
+    for (index, line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let mut words = line.split_whitespace();
+        let keyword = words.next().unwrap_or("");
+        let args: Vec<&str> = words.collect();
+        if let Some(row) = bitmap_row {
+            if keyword == "ENDCHAR" {
+                if let Some(code_point) = encoding.take() {
+                    glyphs.insert(code_point, cell);
+                }
+                bitmap_row = None;
+                continue;
+            }
+            let (font_box, glyph_box) = (font_box.unwrap(), glyph_box.unwrap());
+            place_row(line_number, keyword, row, font_box, glyph_box, &mut cell)?;
+            bitmap_row = Some(row + 1);
+            continue;
+        }
+        match keyword {
+            "FONTBOUNDINGBOX" => {
+                font_box = Some(parse_bounding_box(line_number, &args)?);
+            }
+            "STARTCHAR" => {
+                encoding = None;
+                glyph_box = font_box;
+                cell = [[0; 5]; 5];
+            }
+            "ENCODING" => {
+                let value = args.first()
+                    .and_then(|arg| arg.parse::<i64>().ok())
+                    .ok_or_else(|| Error::at_line(line_number, "bad encoding"))?;
+                encoding = u32::try_from(value).ok();
+            }
+            "BBX" => {
+                glyph_box = Some(parse_bounding_box(line_number, &args)?);
+            }
+            "BITMAP" => {
+                if font_box.is_none() {
+                    return Err(Error::at_line(line_number, "no FONTBOUNDINGBOX"));
+                }
+                bitmap_row = Some(0);
+            }
+            _ => (),
+        }
+    }
+    if bitmap_row.is_some() {
+        return Err(Error::new("missing ENDCHAR at end of file"));
+    }
+    Ok(glyphs)
+}
@@ -0,0 +1,107 @@
+//! Writing images as Rust source.
+//!
+//! The output uses `BitImage::new()` and `GreyscaleImage::new()`, so it can
+//! be used in `const` and `static` items. The caller is responsible for
+//! bringing the image type into scope, eg with
+//! `use rmicrobit::graphics::image::BitImage;`.
+
+use crate::bdf::Glyphs;
+use crate::{Error, Pixels};
+use std::fmt::Write;
+
+/// Which `rmicrobit` image type to generate.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ImageType {
+    /// `BitImage`; pixel values must be 0 or 1.
+    Bit,
+    /// `GreyscaleImage`; pixel values must be from 0 to 9.
+    Greyscale,
+}
+
+impl ImageType {
+
+    /// The name of the Rust type.
+    pub fn name(self) -> &'static str {
+        match self {
+            ImageType::Bit => "BitImage",
+            ImageType::Greyscale => "GreyscaleImage",
+        }
+    }
+
+}
+
+/// Converts brightness values to 0 or 1: any nonzero value becomes 1.
+pub fn to_bits(pixels: &Pixels) -> Pixels {
+    let mut bits = [[0; 5]; 5];
+    for (bit_row, row) in bits.iter_mut().zip(pixels) {
+        for (bit, &value) in bit_row.iter_mut().zip(row) {
+            *bit = (value != 0) as u8;
+        }
+    }
+    bits
+}
+
+// Writes an image constructor expression, indented by `indent` spaces,
+// without a trailing newline.
+fn write_image(out: &mut String, indent: usize, image_type: ImageType, pixels: &Pixels) {
+    let pad = " ".repeat(indent);
+    writeln!(out, "{}::new(&[", image_type.name()).unwrap();
+    for row in pixels {
+        let values: Vec<String> = row.iter().map(u8::to_string).collect();
+        writeln!(out, "{}    [{}],", pad, values.join(", ")).unwrap();
+    }
+    write!(out, "{}])", pad).unwrap();
+}
+
+/// Returns a `pub const` item for a single image.
+pub fn image_const(name: &str, image_type: ImageType, pixels: &Pixels) -> String {
+    let mut out = String::new();
+    write!(out, "pub const {}: {} = ", name, image_type.name()).unwrap();
+    write_image(&mut out, 0, image_type, pixels);
+    out.push_str(";\n");
+    out
+}
+
+/// Returns a `pub static` array item for a sequence of images.
+pub fn image_array(name: &str, image_type: ImageType, images: &[Pixels]) -> String {
+    let mut out = String::new();
+    writeln!(out, "pub static {}: [{}; {}] = [",
+             name, image_type.name(), images.len()).unwrap();
+    for pixels in images {
+        out.push_str("    ");
+        write_image(&mut out, 4, image_type, pixels);
+        out.push_str(",\n");
+    }
+    out.push_str("];\n");
+    out
+}
+
+/// Returns a `pub static` array item of `BitImage`s for the code points
+/// from `first` to `last` (inclusive) of a font.
+///
+/// Each glyph is preceded by a comment showing its character.
+///
+/// Returns an error if the font doesn't have a glyph for one of the code
+/// points.
+pub fn font_table(name: &str, glyphs: &Glyphs, first: u32, last: u32)
+-> Result<String, Error> {
+    if first > last {return Err(Error::new("empty range of code points"))}
+    let mut out = String::new();
+    let count = last - first + 1;
+    writeln!(out, "pub static {}: [BitImage; {}] = [", name, count).unwrap();
+    for code_point in first..=last {
+        let pixels = glyphs.get(&code_point).ok_or_else(|| {
+            Error::new(format!("font has no glyph for U+{:04X}", code_point))
+        })?;
+        write!(out, "    // U+{:04X}", code_point).unwrap();
+        match std::char::from_u32(code_point) {
+            Some(c) if !c.is_control() => writeln!(out, " {:?}", c).unwrap(),
+            _ => out.push('\n'),
+        }
+        out.push_str("    ");
+        write_image(&mut out, 4, ImageType::Bit, pixels);
+        out.push_str(",\n");
+    }
+    out.push_str("];\n");
+    Ok(out)
+}
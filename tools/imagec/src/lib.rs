@@ -0,0 +1,111 @@
+//! Converts fonts and images into Rust source for `rmicrobit`'s
+//! `BitImage` and `GreyscaleImage` types.
+//!
+//! This runs on the development machine, not the micro:bit.
+//!
+//! Supported input formats:
+//!
+//! - BDF fonts (see [`bdf`]);
+//! - PBM and PGM images, in either the plain or the raw variant (see
+//!   [`netpbm`]);
+//! - text files in `rmicrobit`'s ascii-art format (see [`ascii_art`]).
+//!
+//! The [`codegen`] module writes the resulting images as Rust constants.
+//!
+//! # Running
+//!
+//! The `rmicrobit` working copy's cargo configuration builds for the
+//! micro:bit by default, so give your development machine's target
+//! explicitly:
+//!
+//! ```text
+//! cd tools/imagec
+//! cargo run --target x86_64-unknown-linux-gnu -- font fonts/pendolino.bdf
+//! ```
+//!
+//! See `src/main.rs` for the command-line options.
+//!
+//! The `fonts/pendolino.bdf` file is a copy of the font used by
+//! `rmicrobit::graphics::font::Pendolino`; a test checks that the two agree.
+
+pub mod ascii_art;
+pub mod bdf;
+pub mod codegen;
+pub mod netpbm;
+
+use std::fmt;
+
+#[cfg(test)]
+#[path = "../unit_tests/round_trip_tests.rs"]
+mod round_trip_tests;
+
+/// Brightness values for a 5×5 image, indexed by row then column.
+///
+/// For images which will become `BitImage`s, each value is 0 or 1; for
+/// `GreyscaleImage`s each value is from 0 to 9.
+pub type Pixels = [[u8; 5]; 5];
+
+/// The maximum brightness of a `GreyscaleImage` pixel.
+pub const MAX_BRIGHTNESS: u8 = 9;
+
+/// An error from reading an input file.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Error {
+    /// The line number (starting from 1) where the problem was found, if the
+    /// input is line-based.
+    pub line: Option<usize>,
+    /// A description of the problem.
+    pub message: String,
+}
+
+impl Error {
+
+    pub(crate) fn new(message: impl Into<String>) -> Error {
+        Error {line: None, message: message.into()}
+    }
+
+    pub(crate) fn at_line(line: usize, message: impl Into<String>) -> Error {
+        Error {line: Some(line), message: message.into()}
+    }
+
+}
+
+impl fmt::Display for Error {
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+
+}
+
+impl std::error::Error for Error {}
+
+/// Splits an image whose width and height are multiples of 5 into 5×5
+/// tiles.
+///
+/// `pixel(x, y)` returns the brightness at (x, y). The tiles are returned in
+/// reading order: left to right, then top to bottom.
+pub fn split_tiles(width: usize, height: usize, pixel: impl Fn(usize, usize) -> u8)
+-> Result<Vec<Pixels>, Error> {
+    if width == 0 || height == 0 || width % 5 != 0 || height % 5 != 0 {
+        return Err(Error::new(format!(
+            "image is {}×{}; width and height must be multiples of 5",
+            width, height)));
+    }
+    let mut tiles = Vec::new();
+    for tile_y in (0..height).step_by(5) {
+        for tile_x in (0..width).step_by(5) {
+            let mut tile = [[0; 5]; 5];
+            for (y, row) in tile.iter_mut().enumerate() {
+                for (x, value) in row.iter_mut().enumerate() {
+                    *value = pixel(tile_x + x, tile_y + y);
+                }
+            }
+            tiles.push(tile);
+        }
+    }
+    Ok(tiles)
+}
@@ -0,0 +1,152 @@
+//! Command-line interface for imagec.
+//!
+//! ```text
+//! imagec font [--name NAME] [--range FIRST-LAST] FILE.bdf
+//! imagec image [--name NAME] [--bit | --greyscale] FILE
+//! ```
+//!
+//! The generated Rust source is written to standard output.
+//!
+//! For `font`, the range defaults to the ascii printable characters
+//! (`0x20-0x7E`); code points can be given in decimal or in hex with a `0x`
+//! prefix.
+//!
+//! For `image`, files with extension `.pbm`, `.pgm` or `.pnm` are read as
+//! Netpbm images; anything else is read as ascii art. PBM files become
+//! `BitImage`s and the others become `GreyscaleImage`s, unless `--bit` or
+//! `--greyscale` is given (`--bit` treats any nonzero brightness as 'on').
+//! A file containing a single 5×5 image produces a `const`; otherwise the
+//! result is a `static` array.
+
+use imagec::codegen::{self, ImageType};
+use imagec::{ascii_art, bdf, netpbm};
+use std::path::Path;
+use std::process::exit;
+
+const USAGE: &str = "\
+usage: imagec font [--name NAME] [--range FIRST-LAST] FILE.bdf
+       imagec image [--name NAME] [--bit | --greyscale] FILE";
+
+struct Options {
+    name: Option<String>,
+    range: (u32, u32),
+    image_type: Option<ImageType>,
+    path: String,
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("imagec: {}\n{}", message, USAGE);
+    exit(2);
+}
+
+fn parse_code_point(s: &str) -> Option<u32> {
+    match s.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None => s.parse().ok(),
+    }
+}
+
+fn parse_options(args: &[String]) -> Options {
+    let mut options = Options {
+        name: None,
+        range: (0x20, 0x7E),
+        image_type: None,
+        path: String::new(),
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--name" => {
+                let name = args.next().unwrap_or_else(|| usage_error("--name needs a value"));
+                options.name = Some(name.clone());
+            }
+            "--range" => {
+                let range = args.next().unwrap_or_else(|| usage_error("--range needs a value"));
+                let bounds = range.split_once('-')
+                    .and_then(|(first, last)| Some((
+                        parse_code_point(first)?,
+                        parse_code_point(last)?,
+                    )));
+                options.range = bounds.unwrap_or_else(|| usage_error("bad --range"));
+            }
+            "--bit" => options.image_type = Some(ImageType::Bit),
+            "--greyscale" => options.image_type = Some(ImageType::Greyscale),
+            _ if arg.starts_with("--") => usage_error(&format!("unknown option {}", arg)),
+            _ if options.path.is_empty() => options.path = arg.clone(),
+            _ => usage_error("too many arguments"),
+        }
+    }
+    if options.path.is_empty() {usage_error("no input file")}
+    options
+}
+
+// Returns a default item name based on the input file's name, eg
+// "heart.pbm" becomes "HEART".
+fn default_name(path: &str) -> String {
+    let stem = Path::new(path).file_stem().and_then(|s| s.to_str()).unwrap_or("");
+    let name: String = stem.chars()
+        .map(|c| if c.is_ascii_alphanumeric() {c.to_ascii_uppercase()} else {'_'})
+        .collect();
+    match name.chars().next() {
+        Some(c) if !c.is_ascii_digit() => name,
+        _ => format!("IMAGE_{}", name),
+    }
+}
+
+fn run(command: &str, options: &Options) -> Result<String, String> {
+    let data = std::fs::read(&options.path)
+        .map_err(|e| format!("can't read {}: {}", options.path, e))?;
+    let name = options.name.clone().unwrap_or_else(|| default_name(&options.path));
+    let in_file = |e: imagec::Error| format!("{}: {}", options.path, e);
+    match command {
+        "font" => {
+            let text = String::from_utf8(data).map_err(|_| "font isn't utf-8".to_string())?;
+            let glyphs = bdf::parse(&text).map_err(in_file)?;
+            let (first, last) = options.range;
+            codegen::font_table(&name, &glyphs, first, last).map_err(in_file)
+        }
+        "image" => {
+            let extension = Path::new(&options.path).extension()
+                .and_then(|s| s.to_str())
+                .map(str::to_ascii_lowercase);
+            let (default_type, images) = match extension.as_deref() {
+                Some("pbm") | Some("pgm") | Some("pnm") => {
+                    let (kind, images) = netpbm::parse(&data).map_err(in_file)?;
+                    match kind {
+                        netpbm::Kind::Bitmap => (ImageType::Bit, images),
+                        netpbm::Kind::Greymap => (ImageType::Greyscale, images),
+                    }
+                }
+                _ => {
+                    let text = String::from_utf8(data)
+                        .map_err(|_| "file isn't utf-8".to_string())?;
+                    (ImageType::Greyscale, ascii_art::parse(&text).map_err(in_file)?)
+                }
+            };
+            let image_type = options.image_type.unwrap_or(default_type);
+            let images: Vec<_> = match image_type {
+                ImageType::Bit => images.iter().map(codegen::to_bits).collect(),
+                ImageType::Greyscale => images,
+            };
+            match images[..] {
+                [] => Err(format!("{}: no images found", options.path)),
+                [pixels] => Ok(codegen::image_const(&name, image_type, &pixels)),
+                _ => Ok(codegen::image_array(&name, image_type, &images)),
+            }
+        }
+        _ => usage_error(&format!("unknown command {}", command)),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let command = args.first().unwrap_or_else(|| usage_error("no command given"));
+    let options = parse_options(&args[1..]);
+    match run(command, &options) {
+        Ok(source) => print!("{}", source),
+        Err(message) => {
+            eprintln!("imagec: {}", message);
+            exit(1);
+        }
+    }
+}
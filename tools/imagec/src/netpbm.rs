@@ -0,0 +1,148 @@
+//! Reading PBM (bitmap) and PGM (greymap) images.
+//!
+//! Both the plain (`P1`, `P2`) and raw (`P4`, `P5`) variants are supported.
+//! Raw PGM files must use one byte per sample (a maxval of at most 255).
+//!
+//! The width and height of the image must be multiples of 5; larger images
+//! are split into 5×5 tiles (see [`split_tiles()`]).
+//!
+//! [`split_tiles()`]: crate::split_tiles
+
+use crate::{split_tiles, Error, Pixels, MAX_BRIGHTNESS};
+
+#[cfg(test)]
+#[path = "../unit_tests/netpbm_tests.rs"]
+mod tests;
+
+/// The kind of image read from a file.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Kind {
+    /// A PBM file; the pixel values are 0 and 1.
+    Bitmap,
+    /// A PGM file; the pixel values are from 0 to `MAX_BRIGHTNESS`.
+    Greymap,
+}
+
+// Reads whitespace-separated header fields and plain-format samples,
+// skipping comments.
+struct Tokens<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Tokens<'a> {
+
+    fn skip_whitespace(&mut self) {
+        while let Some(&byte) = self.data.get(self.position) {
+            if byte == b'#' {
+                while self.data.get(self.position).map_or(false, |&b| b != b'\n') {
+                    self.position += 1;
+                }
+            } else if byte.is_ascii_whitespace() {
+                self.position += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn next_token(&mut self) -> Result<&'a [u8], Error> {
+        self.skip_whitespace();
+        let start = self.position;
+        while self.data.get(self.position)
+            .map_or(false, |&b| !b.is_ascii_whitespace() && b != b'#') {
+            self.position += 1;
+        }
+        if start == self.position {return Err(Error::new("unexpected end of file"))}
+        Ok(&self.data[start..self.position])
+    }
+
+    fn next_number(&mut self) -> Result<usize, Error> {
+        let token = self.next_token()?;
+        std::str::from_utf8(token).ok()
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| Error::new("bad number"))
+    }
+
+    // In plain PBM, samples needn't be separated by whitespace.
+    fn next_bit(&mut self) -> Result<usize, Error> {
+        self.skip_whitespace();
+        match self.data.get(self.position) {
+            Some(b'0') => {self.position += 1; Ok(0)}
+            Some(b'1') => {self.position += 1; Ok(1)}
+            Some(_) => Err(Error::new("bad sample")),
+            None => Err(Error::new("unexpected end of file")),
+        }
+    }
+
+}
+
+/// Scales a PGM sample to the range 0 to `MAX_BRIGHTNESS`, rounding to the
+/// nearest level.
+pub fn scale_sample(sample: usize, maxval: usize) -> u8 {
+    ((sample * MAX_BRIGHTNESS as usize + maxval / 2) / maxval) as u8
+}
+
+/// Reads a PBM or PGM image, splitting it into 5×5 tiles.
+///
+/// In a PBM file, 1 means 'on' (black, in the file's own terms).
+pub fn parse(data: &[u8]) -> Result<(Kind, Vec<Pixels>), Error> {
+    let mut tokens = Tokens {data, position: 0};
+    let magic = tokens.next_token()?;
+    let (kind, raw) = match magic {
+        b"P1" => (Kind::Bitmap, false),
+        b"P2" => (Kind::Greymap, false),
+        b"P4" => (Kind::Bitmap, true),
+        b"P5" => (Kind::Greymap, true),
+        _ => return Err(Error::new("not a PBM or PGM file")),
+    };
+    let width = tokens.next_number()?;
+    let height = tokens.next_number()?;
+    let maxval = match kind {
+        Kind::Bitmap => 1,
+        Kind::Greymap => tokens.next_number()?,
+    };
+    if width == 0 || height == 0 {
+        return Err(Error::new("empty image"));
+    }
+    if maxval == 0 || (raw && maxval > 255) {
+        return Err(Error::new("unsupported maxval"));
+    }
+    let mut samples = Vec::with_capacity(width * height);
+    if raw {
+        // exactly one whitespace byte separates the header from the data
+        let start = tokens.position + 1;
+        let row_bytes = match kind {
+            Kind::Bitmap => (width + 7) / 8,
+            Kind::Greymap => width,
+        };
+        let raster = data.get(start..start + row_bytes * height)
+            .ok_or_else(|| Error::new("unexpected end of file"))?;
+        for row in raster.chunks(row_bytes) {
+            for x in 0..width {
+                samples.push(match kind {
+                    Kind::Bitmap => ((row[x / 8] >> (7 - x % 8)) & 1) as usize,
+                    Kind::Greymap => row[x] as usize,
+                });
+            }
+        }
+    } else {
+        for _ in 0..width * height {
+            samples.push(match kind {
+                Kind::Bitmap => tokens.next_bit()?,
+                Kind::Greymap => tokens.next_number()?,
+            });
+        }
+    }
+    if samples.iter().any(|&sample| sample > maxval) {
+        return Err(Error::new("sample greater than maxval"));
+    }
+    let tiles = split_tiles(width, height, |x, y| {
+        let sample = samples[y * width + x];
+        match kind {
+            Kind::Bitmap => sample as u8,
+            Kind::Greymap => scale_sample(sample, maxval),
+        }
+    })?;
+    Ok((kind, tiles))
+}
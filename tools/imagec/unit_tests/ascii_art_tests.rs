@@ -0,0 +1,34 @@
+use super::*;
+
+#[test]
+fn test_several_images() {
+    let images = parse("
+        .....  #....
+        .#.#.  .#...
+        .....  ..9..
+        #...#  ...5.
+        .###.  ....1
+
+        12345
+        .....
+        .....
+        .....
+        .....
+    ".replace("  ", "").as_str()).unwrap();
+    assert_eq!(images.len(), 3);
+    assert_eq!(images[0][3], [9, 0, 0, 0, 9]);
+    assert_eq!(images[1][4], [0, 0, 0, 0, 1]);
+    assert_eq!(images[2][0], [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_errors() {
+    let error = parse("\n.....\n.....\n..x..\n.....\n.....\n").unwrap_err();
+    assert_eq!(error.line, Some(4));
+    let error = parse(".....\n.....\n....\n.....\n.....\n").unwrap_err();
+    assert_eq!(error.line, Some(3));
+    let error = parse(".....\n.....\n").unwrap_err();
+    assert_eq!(error.line, Some(1));
+    let error = parse("......\n......\n......\n......\n......\n").unwrap_err();
+    assert_eq!(error.line, Some(1));
+}
@@ -0,0 +1,64 @@
+use super::*;
+
+const FONT: &str = "\
+STARTFONT 2.1
+FONTBOUNDINGBOX 5 5 0 -1
+CHARS 2
+STARTCHAR period
+ENCODING 46
+BBX 1 1 2 0
+BITMAP
+80
+ENDCHAR
+STARTCHAR comma
+ENCODING 44
+BBX 2 2 1 -1
+BITMAP
+40
+80
+ENDCHAR
+STARTCHAR unencoded
+ENCODING -1
+BBX 1 1 0 0
+BITMAP
+80
+ENDCHAR
+ENDFONT
+";
+
+#[test]
+fn test_placement() {
+    let glyphs = parse(FONT).unwrap();
+    assert_eq!(glyphs.len(), 2);
+    assert_eq!(glyphs[&46], [
+        [0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0],
+        [0, 0, 1, 0, 0],
+        [0, 0, 0, 0, 0],
+    ]);
+    assert_eq!(glyphs[&44][3], [0, 0, 1, 0, 0]);
+    assert_eq!(glyphs[&44][4], [0, 1, 0, 0, 0]);
+}
+
+#[test]
+fn test_too_big() {
+    // The comma's descender falls below the 5×5 cell.
+    let font = FONT.replace("FONTBOUNDINGBOX 5 5 0 -1", "FONTBOUNDINGBOX 5 5 0 0");
+    let error = parse(&font).unwrap_err();
+    assert_eq!(error.line, Some(15));
+}
+
+#[test]
+fn test_bad_bitmap() {
+    let font = FONT.replace("40\n", "4\n");
+    assert_eq!(parse(&font).unwrap_err().line, Some(14));
+    let font = FONT.replace("40\n", "4G\n");
+    assert_eq!(parse(&font).unwrap_err().line, Some(14));
+}
+
+#[test]
+fn test_missing_endchar() {
+    let font = FONT.replace("80\nENDCHAR\nENDFONT\n", "80\n");
+    assert_eq!(parse(&font).unwrap_err().line, None);
+}
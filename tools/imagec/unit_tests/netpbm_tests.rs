@@ -0,0 +1,55 @@
+use super::*;
+
+const CROSS: Pixels = [
+    [1, 0, 0, 0, 1],
+    [0, 1, 0, 1, 0],
+    [0, 0, 1, 0, 0],
+    [0, 1, 0, 1, 0],
+    [1, 0, 0, 0, 1],
+];
+
+#[test]
+fn test_plain_pbm() {
+    let data = b"P1\n# a cross\n5 5\n10001\n01010\n00100\n0 1 0 1 0\n10001\n";
+    assert_eq!(parse(data).unwrap(), (Kind::Bitmap, vec![CROSS]));
+}
+
+#[test]
+fn test_raw_pbm() {
+    let mut data = b"P4 10 5\n".to_vec();
+    // each row is padded to a whole byte; the second tile is blank
+    data.extend([0x88, 0x00, 0x50, 0x00, 0x20, 0x00, 0x50, 0x00, 0x88, 0x00]);
+    let (kind, tiles) = parse(&data).unwrap();
+    assert_eq!(kind, Kind::Bitmap);
+    assert_eq!(tiles, vec![CROSS, [[0; 5]; 5]]);
+}
+
+#[test]
+fn test_pgm_scaling() {
+    let data = b"P2 5 5 255\n\
+        0 14 15 28 29\n\
+        127 128 240 241 255\n\
+        0 0 0 0 0\n0 0 0 0 0\n0 0 0 0 0\n";
+    let (kind, tiles) = parse(data).unwrap();
+    assert_eq!(kind, Kind::Greymap);
+    assert_eq!(tiles[0][0], [0, 0, 1, 1, 1]);
+    assert_eq!(tiles[0][1], [4, 5, 8, 9, 9]);
+}
+
+#[test]
+fn test_raw_pgm() {
+    let mut data = b"P5 5 5 9\n".to_vec();
+    data.extend(0..25);
+    assert_eq!(parse(&data).unwrap_err().message, "sample greater than maxval");
+    let mut data = b"P5 5 5 24\n".to_vec();
+    data.extend(0..25);
+    let (_, tiles) = parse(&data).unwrap();
+    assert_eq!(tiles[0][4], [8, 8, 8, 9, 9]);
+}
+
+#[test]
+fn test_bad_size() {
+    assert!(parse(b"P1 4 5\n00000000000000000000").is_err());
+    assert!(parse(b"P1 0 5\n").is_err());
+    assert!(parse(b"P4 5 5\n\x00").is_err());
+}
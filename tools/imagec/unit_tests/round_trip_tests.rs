@@ -0,0 +1,61 @@
+use crate::bdf;
+use crate::codegen;
+use crate::Pixels;
+
+const PENDOLINO_BDF: &str = include_str!("../fonts/pendolino.bdf");
+const PENDOLINO_RS: &str = include_str!("../../../src/graphics/font/pendolino.rs");
+
+// Decodes the font_entry() calls in rmicrobit's pendolino.rs, in the same
+// way as font_entry() itself.
+fn pendolino_glyphs() -> Vec<Pixels> {
+    PENDOLINO_RS.split("font_entry([").skip(1).map(|entry| {
+        let bytes = entry.split(']').next().unwrap();
+        let mut pixels = [[0; 5]; 5];
+        for (row, byte) in pixels.iter_mut().zip(bytes.split(',')) {
+            let byte = u8::from_str_radix(byte.trim().trim_start_matches("0x"), 16).unwrap();
+            for (x, pixel) in row.iter_mut().enumerate() {
+                *pixel = (byte >> (4 - x)) & 1;
+            }
+        }
+        pixels
+    }).collect()
+}
+
+// Reads back the rows of each image in generated source.
+fn generated_glyphs(source: &str) -> Vec<Pixels> {
+    source.split("::new(&[").skip(1).map(|image| {
+        let mut pixels = [[0; 5]; 5];
+        let rows = image.lines().skip(1).take(5);
+        for (row, line) in pixels.iter_mut().zip(rows) {
+            let values = line.trim().trim_start_matches('[').trim_end_matches("],");
+            for (pixel, value) in row.iter_mut().zip(values.split(", ")) {
+                *pixel = value.parse().unwrap();
+            }
+        }
+        pixels
+    }).collect()
+}
+
+#[test]
+fn test_pendolino_bdf_matches_font() {
+    let expected = pendolino_glyphs();
+    assert_eq!(expected.len(), 95);
+    let glyphs = bdf::parse(PENDOLINO_BDF).unwrap();
+    let from_bdf: Vec<Pixels> = (0x20..=0x7E).map(|c| glyphs[&c]).collect();
+    assert_eq!(from_bdf, expected);
+}
+
+#[test]
+fn test_pendolino_round_trip() {
+    let glyphs = bdf::parse(PENDOLINO_BDF).unwrap();
+    let source = codegen::font_table("PENDOLINO", &glyphs, 0x20, 0x7E).unwrap();
+    assert!(source.starts_with("pub static PENDOLINO: [BitImage; 95] = [\n"));
+    assert!(source.contains("    // U+0041 'A'\n    BitImage::new(&[\n"));
+    assert_eq!(generated_glyphs(&source), pendolino_glyphs());
+}
+
+#[test]
+fn test_missing_glyph() {
+    let glyphs = bdf::parse(PENDOLINO_BDF).unwrap();
+    assert!(codegen::font_table("PENDOLINO", &glyphs, 0x20, 0x7F).is_err());
+}
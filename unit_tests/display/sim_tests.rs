@@ -0,0 +1,188 @@
+use super::*;
+
+struct Gradient();
+
+impl Render for Gradient {
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        ((x + y*5) % 10) as u8
+    }
+}
+
+struct SinglePixel(usize, usize);
+
+impl Render for SinglePixel {
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        if (x, y) == (self.0, self.1) {9} else {0}
+    }
+}
+
+// The row and column pins (numbered from 1, as on the micro:bit schematic)
+// connected to the LED at each (x, y).
+const SCHEMATIC: [[(u32, u32); 5]; 5] = [
+    [(1, 1), (2, 4), (1, 2), (2, 5), (1, 3)],
+    [(3, 4), (3, 5), (3, 6), (3, 7), (3, 8)],
+    [(2, 2), (1, 9), (2, 3), (3, 9), (2, 1)],
+    [(1, 8), (1, 7), (1, 6), (1, 5), (1, 4)],
+    [(3, 3), (2, 7), (3, 1), (2, 6), (3, 2)],
+];
+
+// GPIO numbers of schematic row pin 1 (ROW1 is P0.13) and column pin 1 (COL1
+// is P0.04).
+const ROW1_GPIO: u32 = 13;
+const COL1_GPIO: u32 = 4;
+
+// Ticks each LED is lit for at each brightness level over a refresh, from
+// tiny-led-matrix's timing table.
+const LEVEL_TICKS: [u32; 10] = [0, 2, 4, 8, 15, 28, 53, 102, 199, 375];
+
+fn show(sim: &mut SimulatedDisplay, image: &impl Render) {
+    let mut frame = MicrobitFrame::default();
+    frame.set(image);
+    sim.set_frame(&frame);
+    sim.run_refresh();
+    sim.clear_on_ticks();
+    sim.run_refresh();
+}
+
+#[test]
+fn test_initially_blank() {
+    let mut sim = SimulatedDisplay::new();
+    sim.run_refresh();
+    for y in 0..5 {
+        for x in 0..5 {
+            assert_eq!(sim.port().on_ticks(x, y), 0);
+            assert_eq!(sim.perceived_brightness_at(x, y), 0);
+        }
+    }
+}
+
+#[test]
+fn test_levels_are_distinct() {
+    let mut sim = SimulatedDisplay::new();
+    show(&mut sim, &Gradient());
+    for level in 1..10 {
+        assert!(sim.port().on_ticks(level % 5, level / 5) >
+                sim.port().on_ticks((level-1) % 5, (level-1) / 5));
+    }
+}
+
+#[test]
+fn test_full_brightness_is_one_cycle() {
+    let mut sim = SimulatedDisplay::new();
+    show(&mut sim, &SinglePixel(2, 2));
+    assert_eq!(sim.timer().cycle_ticks(), 375);
+    assert_eq!(sim.port().on_ticks(2, 2), 375);
+}
+
+#[test]
+fn test_level_on_ticks() {
+    let mut sim = SimulatedDisplay::new();
+    show(&mut sim, &Gradient());
+    for y in 0..5 {
+        for x in 0..5 {
+            let level = Gradient().brightness_at(x, y);
+            assert_eq!(sim.port().on_ticks(x, y), LEVEL_TICKS[level as usize],
+                       "level {} at ({}, {})", level, x, y);
+        }
+    }
+}
+
+#[test]
+fn test_perceived_image() {
+    let mut sim = SimulatedDisplay::new();
+    show(&mut sim, &Gradient());
+    let image = sim.perceived_image();
+    for y in 0..5 {
+        for x in 0..5 {
+            assert_eq!(image.brightness_at(x, y), Gradient().brightness_at(x, y));
+        }
+    }
+}
+
+#[test]
+fn test_layout() {
+    let mut sim = SimulatedDisplay::new();
+    for y in 0..5 {
+        for x in 0..5 {
+            show(&mut sim, &SinglePixel(x, y));
+            for yy in 0..5 {
+                for xx in 0..5 {
+                    let expected = if (xx, yy) == (x, y) {9} else {0};
+                    assert_eq!(sim.perceived_brightness_at(xx, yy), expected,
+                               "lit ({}, {}), checking ({}, {})", x, y, xx, yy);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_layout_matches_schematic() {
+    let mut sim = SimulatedDisplay::new();
+    for (y, pin_pairs) in SCHEMATIC.iter().enumerate() {
+        for (x, &(row_pin, col_pin)) in pin_pairs.iter().enumerate() {
+            let mut frame = MicrobitFrame::default();
+            frame.set(&SinglePixel(x, y));
+            sim.set_frame(&frame);
+            // A full-brightness LED stays lit until the end of its row's
+            // cycle, so looking at the pins after each cycle finds it.
+            let mut cycles_lit = 0;
+            for _ in 0..3 {
+                sim.run_cycle();
+                let pins = sim.port().pins();
+                let lit_cols = !pins & COL_PINS_MASK;
+                if pins & 1<<(ROW1_GPIO + row_pin - 1) != 0 {
+                    assert_eq!(lit_cols, 1<<(COL1_GPIO + col_pin - 1),
+                               "({}, {}) should be row {} col {}", x, y, row_pin, col_pin);
+                    cycles_lit += 1;
+                } else {
+                    assert_eq!(lit_cols, 0, "({}, {}) lit the wrong row", x, y);
+                }
+            }
+            assert_eq!(cycles_lit, 1, "({}, {}) should be lit in one cycle", x, y);
+        }
+    }
+}
+
+#[test]
+fn test_is_lit() {
+    let mut sim = SimulatedDisplay::new();
+    let mut frame = MicrobitFrame::default();
+    frame.set(&SinglePixel(4, 4));
+    sim.set_frame(&frame);
+    // (4, 4) is row 3 col 2
+    let mut cycles_lit = 0;
+    for _ in 0..3 {
+        sim.run_cycle();
+        let row3_active = sim.port().pins() & 1<<(ROW1_GPIO + 2) != 0;
+        assert_eq!(sim.port().is_lit(4, 4), row3_active);
+        assert!(!sim.port().is_lit(0, 0));
+        if row3_active {cycles_lit += 1}
+    }
+    assert_eq!(cycles_lit, 1);
+}
+
+#[test]
+fn test_set_frame_waits_for_next_cycle() {
+    let mut sim = SimulatedDisplay::new();
+    let mut frame = MicrobitFrame::default();
+    frame.set(&SinglePixel(0, 0));
+    sim.set_frame(&frame);
+    assert_eq!(sim.port().pins() & ROW_PINS_MASK, 1<<row_pin_number(0));
+    assert!(!sim.port().is_lit(0, 0));
+    sim.run_refresh();
+    assert!(sim.port().on_ticks(0, 0) > 0);
+}
+
+#[test]
+fn test_orientation() {
+    let mut sim = SimulatedDisplay::new();
+    sim.set_orientation(Transform::Rotate90);
+    assert_eq!(sim.orientation(), Transform::Rotate90);
+    sim.set_image(&SinglePixel(0, 0));
+    sim.run_refresh();
+    sim.clear_on_ticks();
+    sim.run_refresh();
+    assert_eq!(sim.perceived_brightness_at(4, 0), 9);
+    assert_eq!(sim.perceived_brightness_at(0, 0), 0);
+}
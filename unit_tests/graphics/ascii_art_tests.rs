@@ -0,0 +1,90 @@
+use super::*;
+use crate::graphics::test_support::assert_image;
+use crate::graphics::image::BitImage;
+use crate::graphics::scrolling::Animate;
+use crate::graphics::scrolling_text::ScrollingStaticText;
+
+const HEART: GreyscaleImage = GreyscaleImage::new(&[
+    [0, 9, 0, 9, 0],
+    [9, 5, 9, 5, 9],
+    [9, 5, 5, 5, 9],
+    [0, 9, 5, 9, 0],
+    [0, 0, 9, 0, 0],
+]);
+
+#[test]
+fn test_format() {
+    assert_eq!(AsciiArt(&HEART).to_string(),
+               ".9.9.\n95959\n95559\n.959.\n..9..");
+}
+
+#[test]
+fn test_format_bit_image() {
+    let image = BitImage::new(&[
+        [1, 0, 0, 0, 0],
+        [0, 1, 0, 0, 0],
+        [0, 0, 1, 0, 0],
+        [0, 0, 0, 1, 0],
+        [0, 0, 0, 0, 1],
+    ]);
+    assert_eq!(AsciiArt(&image).to_string(),
+               "9....\n.9...\n..9..\n...9.\n....9");
+}
+
+#[test]
+fn test_round_trip() {
+    let text = AsciiArt(&HEART).to_string();
+    let parsed: GreyscaleImage = text.parse().unwrap();
+    assert_eq!(AsciiArt(&parsed).to_string(), text);
+}
+
+#[test]
+fn test_parse_alternative_glyphs() {
+    let image = parse("
+        #0000
+        .....
+        12345
+        6789#
+        00000
+    ").unwrap();
+    assert_eq!(image.brightness_at(0, 0), 9);
+    assert_eq!(image.brightness_at(1, 0), 0);
+    assert_eq!(image.brightness_at(4, 2), 5);
+    assert_eq!(image.brightness_at(4, 3), 9);
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(parse(".....\n.....\n.....\n.....").unwrap_err(),
+               ParseError::BadRowCount);
+    assert_eq!(parse(".....\n.....\n.....\n.....\n.....\n.....").unwrap_err(),
+               ParseError::BadRowCount);
+    assert_eq!(parse(".....\n....\n.....\n.....\n.....").unwrap_err(),
+               ParseError::BadRowLength(1));
+    assert_eq!(parse(".....\n.....\n..x..\n.....\n.....").unwrap_err(),
+               ParseError::BadGlyph('x'));
+}
+
+#[test]
+fn test_scrolling_snapshots() {
+    let mut scroller = ScrollingStaticText::default();
+    scroller.set_message(b"I");
+    let expected = [
+        ".....\n.....\n.....\n.....\n.....",
+        "....9\n.....\n.....\n.....\n....9",
+        "...99\n....9\n....9\n....9\n...99",
+        "..999\n...9.\n...9.\n...9.\n..999",
+        ".999.\n..9..\n..9..\n..9..\n.999.",
+        "999..\n.9...\n.9...\n.9...\n999..",
+        "99...\n9....\n9....\n9....\n99...",
+        "9....\n.....\n.....\n.....\n9....",
+        ".....\n.....\n.....\n.....\n.....",
+        ".....\n.....\n.....\n.....\n.....",
+        ".....\n.....\n.....\n.....\n.....",
+    ];
+    for frame in expected.iter() {
+        assert_image(&scroller, frame);
+        scroller.tick();
+    }
+    assert!(scroller.is_finished());
+}
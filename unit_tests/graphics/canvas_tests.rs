@@ -0,0 +1,72 @@
+use super::*;
+use crate::graphics::test_support::assert_image;
+use crate::graphics::image::BitImage;
+
+const BOX: BitImage = BitImage::new(&[
+    [1, 1, 1, 0, 0],
+    [1, 0, 1, 0, 0],
+    [1, 1, 1, 0, 0],
+    [0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0],
+]);
+
+#[test]
+fn test_pixels() {
+    let mut canvas: Canvas<8, 6> = Canvas::new();
+    assert_eq!((canvas.width(), canvas.height()), (8, 6));
+    canvas.set_pixel(7, 5, 3);
+    canvas.set_pixel(8, 5, 4);
+    canvas.set_pixel(-1, 0, 4);
+    assert_eq!(canvas.pixel(7, 5), 3);
+    assert_eq!(canvas.pixel(8, 5), 0);
+    assert_eq!(canvas.pixel(-1, 0), 0);
+}
+
+#[test]
+fn test_viewport() {
+    let mut canvas: Canvas<8, 8> = Canvas::new();
+    canvas.draw(5, 4, &BOX);
+    assert_image(&canvas, "
+        .....
+        .....
+        .....
+        .....
+        .....
+    ");
+    canvas.set_viewport(4, 3);
+    assert_image(&canvas, "
+        .....
+        .###.
+        .#.#.
+        .###.
+        .....
+    ");
+    canvas.set_viewport(6, 4);
+    assert_image(&canvas, "
+        ##...
+        .#...
+        ##...
+        .....
+        .....
+    ");
+}
+
+#[test]
+fn test_pan() {
+    let mut canvas: Canvas<8, 6> = Canvas::new();
+    canvas.draw(0, 0, &BOX);
+    canvas.pan_to(3, 1);
+    assert!(!canvas.is_finished());
+    canvas.tick();
+    assert_eq!(canvas.viewport(), (1, 1));
+    canvas.tick();
+    assert_eq!(canvas.viewport(), (2, 1));
+    canvas.tick();
+    assert_eq!(canvas.viewport(), (3, 1));
+    assert!(canvas.is_finished());
+    canvas.tick();
+    assert_eq!(canvas.viewport(), (3, 1));
+    canvas.reset();
+    assert_eq!(canvas.viewport(), (0, 0));
+    assert!(!canvas.is_finished());
+}
@@ -0,0 +1,140 @@
+use super::*;
+use crate::graphics::test_support::assert_image;
+
+#[test]
+fn test_fill_level() {
+    let levels = |value, smooth| -> Vec<u8> {
+        (0..5).map(|index| fill_level(value, 10, 5, index, smooth)).collect()
+    };
+    assert_eq!(levels(0, false), [0, 0, 0, 0, 0]);
+    assert_eq!(levels(4, false), [9, 9, 0, 0, 0]);
+    assert_eq!(levels(5, false), [9, 9, 9, 0, 0]);
+    assert_eq!(levels(12, false), [9, 9, 9, 9, 9]);
+    assert_eq!(levels(5, true), [9, 9, 4, 0, 0]);
+    assert_eq!(levels(7, true), [9, 9, 9, 4, 0]);
+    assert_eq!(fill_level(3, 0, 5, 0, true), 0);
+}
+
+#[test]
+fn test_bar_graph() {
+    let mut graph = BarGraph::new(60, 100, Direction::Up);
+    assert_image(&graph, "
+        .....
+        .....
+        99999
+        99999
+        99999
+    ");
+    graph.set_smooth(true);
+    graph.set_value(50);
+    assert_eq!((graph.value(), graph.max()), (50, 100));
+    assert_image(&graph, "
+        .....
+        .....
+        44444
+        99999
+        99999
+    ");
+    let mut graph = BarGraph::new(1, 5, Direction::Left);
+    assert_image(&graph, "
+        ....9
+        ....9
+        ....9
+        ....9
+        ....9
+    ");
+    graph.set_max(0);
+    assert_image(&graph, "
+        .....
+        .....
+        .....
+        .....
+        .....
+    ");
+}
+
+#[test]
+fn test_gauge() {
+    let mut gauge = Gauge::new(7, 25);
+    assert_image(&gauge, "
+        99999
+        99...
+        .....
+        .....
+        .....
+    ");
+    gauge.set_smooth(true);
+    gauge.set_value(15);
+    gauge.set_max(50);
+    assert_image(&gauge, "
+        99999
+        994..
+        .....
+        .....
+        .....
+    ");
+}
+
+#[test]
+fn test_sparkline_samples() {
+    let mut sparkline: Sparkline<3> = Sparkline::default();
+    assert!(sparkline.is_empty());
+    assert_eq!(sparkline.range(), None);
+    for sample in &[5, -2, 8, 3] {
+        sparkline.push(*sample);
+    }
+    assert_eq!(sparkline.len(), 3);
+    assert_eq!(sparkline.sample(0), Some(3));
+    assert_eq!(sparkline.sample(2), Some(-2));
+    assert_eq!(sparkline.sample(3), None);
+    assert_eq!(sparkline.range(), Some((-2, 8)));
+    // discarding the lowest and highest samples
+    sparkline.push(4);
+    assert_eq!(sparkline.range(), Some((3, 8)));
+    sparkline.push(4);
+    assert_eq!(sparkline.range(), Some((3, 4)));
+    sparkline.clear();
+    assert_eq!(sparkline.sample(0), None);
+    assert_eq!(sparkline.range(), None);
+    sparkline.push(-7);
+    assert_eq!(sparkline.range(), Some((-7, -7)));
+}
+
+#[test]
+fn test_sparkline() {
+    let mut sparkline: Sparkline<8> = Sparkline::new();
+    for sample in &[40, 0, 10, 20, 30, 40] {
+        sparkline.push(*sample);
+    }
+    // the first 40 isn't displayed, but sets the scale
+    assert_image(&sparkline, "
+        ....9
+        ...99
+        ..999
+        .9999
+        99999
+    ");
+    sparkline.set_smooth(true);
+    sparkline.push(25);
+    assert_image(&sparkline, "
+        ...9.
+        ..994
+        .9999
+        99999
+        99999
+    ");
+}
+
+#[test]
+fn test_sparkline_flat() {
+    let mut sparkline: Sparkline<5> = Sparkline::new();
+    sparkline.push(-7);
+    sparkline.push(-7);
+    assert_image(&sparkline, "
+        .....
+        .....
+        .....
+        .....
+        ...99
+    ");
+}
@@ -0,0 +1,92 @@
+use super::*;
+use crate::graphics::ascii_art::parse;
+use crate::graphics::test_support::assert_image;
+use crate::graphics::image::GreyscaleImage;
+
+fn image(text: &str) -> GreyscaleImage {
+    parse(text).unwrap()
+}
+
+const A: &str = "
+    9....
+    .5...
+    ..3..
+    ...1.
+    ....7
+";
+
+const B: &str = "
+    44444
+    44444
+    44444
+    44444
+    44444
+";
+
+#[test]
+fn test_overlay() {
+    assert_image(&Overlay::new(&image(A), &image(B)), "
+        94444
+        45444
+        44344
+        44414
+        44447
+    ");
+}
+
+#[test]
+fn test_masked() {
+    let mask = BitImage::new(&[
+        [1, 1, 1, 1, 1],
+        [0, 0, 0, 0, 0],
+        [1, 1, 1, 1, 1],
+        [0, 0, 0, 0, 0],
+        [1, 0, 1, 0, 1],
+    ]);
+    assert_image(&Masked::new(&image(B), &mask), "
+        44444
+        .....
+        44444
+        .....
+        4.4.4
+    ");
+}
+
+#[test]
+fn test_max_min_add() {
+    assert_image(&Max::new(&image(A), &image(B)), "
+        94444
+        45444
+        44444
+        44444
+        44447
+    ");
+    assert_image(&Min::new(&image(A), &image(B)), "
+        4....
+        .4...
+        ..3..
+        ...1.
+        ....4
+    ");
+    assert_image(&Add::new(&image(A), &image(B)), "
+        94444
+        49444
+        44744
+        44454
+        44449
+    ");
+}
+
+#[test]
+fn test_blend() {
+    let (a, b) = (image(A), image(B));
+    assert_image(&Blend::new(&a, &b, 0), A);
+    assert_image(&Blend::new(&a, &b, 255), B);
+    assert_image(&Blend::new(&a, &b, 128), "
+        62222
+        24222
+        22422
+        22232
+        22225
+    ");
+}
@@ -0,0 +1,180 @@
+use super::*;
+use crate::graphics::test_support::assert_image;
+
+#[test]
+fn test_plot_clips() {
+    let mut image = GreyscaleImage::blank();
+    image.plot(4, 0, 9);
+    image.plot(5, 0, 9);
+    image.plot(-1, 2, 9);
+    image.plot(0, 7, 9);
+    assert_image(&image, "
+        ....9
+        .....
+        .....
+        .....
+        .....
+    ");
+}
+
+#[test]
+fn test_lines() {
+    let mut image = GreyscaleImage::blank();
+    image.line(0, 0, 4, 2, 9);
+    image.line(4, 4, 0, 4, 5);
+    image.line(1, 4, 1, 2, 3);
+    assert_image(&image, "
+        9....
+        .99..
+        .3.99
+        .3...
+        53555
+    ");
+}
+
+#[test]
+fn test_line_clips() {
+    let mut image = GreyscaleImage::blank();
+    image.line(-2, -2, 6, 6, 9);
+    assert_image(&image, "
+        9....
+        .9...
+        ..9..
+        ...9.
+        ....9
+    ");
+}
+
+#[test]
+fn test_rects() {
+    let mut image = GreyscaleImage::blank();
+    image.fill_rect(1, 1, 3, 2, 4);
+    image.rect(0, 0, 5, 4, 9);
+    image.rect(3, 3, 0, 2, 1);
+    assert_image(&image, "
+        99999
+        94449
+        94449
+        99999
+        .....
+    ");
+}
+
+#[test]
+fn test_circles() {
+    let mut image = GreyscaleImage::blank();
+    image.circle(2, 2, 2, 9);
+    assert_image(&image, "
+        .999.
+        9...9
+        9...9
+        9...9
+        .999.
+    ");
+    image.fill_circle(2, 2, 1, 5);
+    assert_image(&image, "
+        .999.
+        9.5.9
+        95559
+        9.5.9
+        .999.
+    ");
+    image.clear();
+    image.circle(0, 0, 0, 9);
+    image.fill_circle(4, 4, 2, 3);
+    assert_image(&image, "
+        9....
+        .....
+        ...33
+        ..333
+        ..333
+    ");
+}
+
+#[test]
+fn test_flood_fill() {
+    let mut image = GreyscaleImage::blank();
+    image.rect(0, 0, 5, 5, 9);
+    image.line(2, 0, 2, 2, 9);
+    image.flood_fill(1, 1, 4);
+    assert_image(&image, "
+        99999
+        94949
+        94949
+        94449
+        99999
+    ");
+    image.flood_fill(0, 0, 2);
+    assert_image(&image, "
+        22222
+        24242
+        24242
+        24442
+        22222
+    ");
+    image.flood_fill(5, 0, 7);
+    image.flood_fill(2, 2, 2);
+    assert_image(&image, "
+        22222
+        24242
+        24242
+        24442
+        22222
+    ");
+}
+
+#[test]
+fn test_flood_fill_any_values() {
+    // the old fill used 255 to mark the region, so pixels which were
+    // already 255 joined it
+    let mut image = GreyscaleImage::blank();
+    image.line(2, 0, 2, 4, 9);
+    image.set_pixel(4, 2, 255);
+    image.set_pixel(0, 4, 254);
+    image.flood_fill(0, 0, 3);
+    assert_eq!(image.pixel(4, 2), 255);
+    assert_eq!(image.pixel(0, 4), 254);
+    assert_image(&image, "
+        339..
+        339..
+        339.9
+        339..
+        939..
+    ");
+}
+
+#[test]
+fn test_flood_fill_overflow() {
+    // filling the top row finds more teeth below it than fit on the stack
+    let mut canvas: Canvas<80, 3> = Canvas::new();
+    for x in (1..80).step_by(2) {
+        canvas.set_pixel(x, 1, 9);
+    }
+    canvas.line(0, 2, 79, 2, 9);
+    canvas.flood_fill(0, 0, 4);
+    for x in 0..80 {
+        assert_eq!(canvas.pixel(x, 0), 4);
+        assert_eq!(canvas.pixel(x, 1), if x % 2 == 0 {4} else {9}, "x = {}", x);
+        assert_eq!(canvas.pixel(x, 2), 9);
+    }
+}
+
+#[test]
+fn test_canvas() {
+    let mut canvas: Canvas<12, 3> = Canvas::new();
+    canvas.line(0, 0, 11, 2, 9);
+    canvas.rect(8, 0, 4, 3, 5);
+    canvas.flood_fill(0, 2, 1);
+    assert_eq!(
+        (0..12).map(|x| canvas.pixel(x, 1)).collect::<Vec<_>>(),
+        [1, 1, 1, 9, 9, 9, 9, 9, 5, 0, 0, 5],
+    );
+    canvas.set_viewport(3, 0);
+    assert_image(&canvas, "
+        .....
+        99999
+        11111
+        .....
+        .....
+    ");
+}
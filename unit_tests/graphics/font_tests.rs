@@ -0,0 +1,116 @@
+use super::*;
+use crate::graphics::test_support::assert_image;
+use crate::graphics::scrolling::{Animate, Scrollable, PROPORTIONAL_BLANK_WIDTH};
+use crate::graphics::scrolling_text::ScrollingStaticText;
+use core::ptr;
+
+#[test]
+fn test_pendolino() {
+    assert!(ptr::eq(Pendolino.glyph('A').unwrap(), character(b'A')));
+    assert!(ptr::eq(Pendolino.glyph('~').unwrap(), character(b'~')));
+    assert!(Pendolino.glyph('\n').is_none());
+    assert!(Pendolino.glyph('Ā').is_none());
+    assert!(ptr::eq(Pendolino.character('Ā'), Pendolino.fallback()));
+    assert!(ptr::eq(character(0), Pendolino.fallback()));
+}
+
+#[test]
+fn test_compact_digits() {
+    assert_image(Compact.character('2'), "
+        .###.
+        ...#.
+        .###.
+        .#...
+        .###.
+    ");
+}
+
+#[test]
+fn test_compact_letters() {
+    assert_image(Compact.character('K'), "
+        .#.#.
+        .#.#.
+        .##..
+        .#.#.
+        .#.#.
+    ");
+    assert!(ptr::eq(Compact.glyph('k').unwrap(), Compact.glyph('K').unwrap()));
+}
+
+#[test]
+fn test_compact_fallback() {
+    assert!(Compact.glyph('@').is_none());
+    assert_image(Compact.character('@'), "
+        #####
+        #...#
+        #...#
+        #...#
+        #####
+    ");
+}
+
+#[test]
+fn test_glyph_extent() {
+    assert_eq!(Pendolino.glyph_extent('I'), (0, 3));
+    assert_eq!(Pendolino.glyph_extent(' '), (0, PROPORTIONAL_BLANK_WIDTH));
+    assert_eq!(Compact.glyph_extent('8'), (1, 3));
+    assert_eq!(Compact.glyph_extent('1'), (1, 3));
+}
+
+#[test]
+fn test_scroll_with_font() {
+    let mut scroller = ScrollingStaticText::with_font(Compact);
+    scroller.set_message(b"12");
+    scroller.set_proportional(true);
+    for _ in 0..6 {
+        scroller.tick();
+    }
+    assert_image(&scroller, "
+        #..##
+        #....
+        #..##
+        #..#.
+        ##.##
+    ");
+    assert_eq!(scroller.length(), 2);
+}
+
+#[test]
+fn test_pendolino_latin1() {
+    assert_image(Pendolino.character('Ä'), "
+        #..#.
+        .##..
+        #..#.
+        ####.
+        #..#.
+    ");
+    assert!(Pendolino.glyph('\u{A0}').is_some());
+    assert!(Pendolino.glyph('ÿ').is_some());
+    assert!(Pendolino.glyph('€').is_some());
+    assert!(Pendolino.glyph('\u{100}').is_none());
+    // font::character() only covers ascii
+    assert!(ptr::eq(character(0xC4), Pendolino.fallback()));
+}
+
+// Pairs of characters which are documented as having the same glyph.
+const SAME_GLYPHS: [(char, char); 4] = [('\u{A0}', ' '), ('\u{AD}', '-'), ('×', '*'), ('¡', 'i')];
+
+fn same_pixels(a: char, b: char) -> bool {
+    let (a, b) = (Pendolino.character(a), Pendolino.character(b));
+    (0..5).all(|y| (0..5).all(|x| a.pixel(x, y) == b.pixel(x, y)))
+}
+
+// Checks each non-ascii glyph against every other glyph, including the ascii
+// letter an accented letter is based on and its other case.
+#[test]
+fn test_pendolino_latin1_distinct() {
+    let latin1 = ('\u{A0}'..='ÿ').chain(Some('€'));
+    let all = (' '..='~').chain(latin1.clone());
+    for c in latin1 {
+        for other in all.clone().filter(|&other| other != c) {
+            let documented = SAME_GLYPHS.contains(&(c, other)) ||
+                SAME_GLYPHS.contains(&(other, c));
+            assert_eq!(same_pixels(c, other), documented, "{:?} and {:?}", c, other);
+        }
+    }
+}
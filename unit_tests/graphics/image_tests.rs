@@ -0,0 +1,210 @@
+use super::*;
+use crate::graphics::ascii_art::AsciiArt;
+use crate::graphics::test_support::assert_image;
+use crate::graphics::transform::{Shifted, Transformed};
+
+const GREY_HEART: GreyscaleImage = GreyscaleImage::new(&[
+    [0, 9, 0, 9, 0],
+    [9, 5, 9, 5, 9],
+    [9, 5, 5, 5, 9],
+    [0, 9, 5, 9, 0],
+    [0, 0, 9, 0, 0],
+]);
+
+const HEART: BitImage = BitImage::from_greyscale_image(&GREY_HEART, 6);
+
+#[test]
+fn test_greyscale_pixels() {
+    let mut image = GreyscaleImage::blank();
+    image.set_pixel(1, 3, 7);
+    image.set_row(0, [1, 2, 3, 4, 5]);
+    image.set_column(4, [9, 9, 9, 9, 9]);
+    assert_eq!(image.pixel(1, 3), 7);
+    assert_image(&image, "
+        12349
+        ....9
+        ....9
+        .7..9
+        ....9
+    ");
+    image.invert();
+    assert_image(&image, "
+        8765.
+        9999.
+        9999.
+        9299.
+        9999.
+    ");
+    image.fill(3);
+    assert_image(&image, &AsciiArt(&GreyscaleImage::filled(3)).to_string());
+    image.clear();
+    assert_image(&image, &AsciiArt(&GreyscaleImage::blank()).to_string());
+}
+
+#[test]
+fn test_bit_pixels() {
+    let mut image = BitImage::blank();
+    image.set_pixel(2, 2, true);
+    image.set_row(0, [1, 0, 1, 0, 1]);
+    image.set_column(0, [1, 1, 1, 1, 1]);
+    assert!(image.pixel(2, 2));
+    assert!(!image.pixel(1, 0));
+    image.set_pixel(2, 0, false);
+    assert_image(&image, "
+        #...#
+        #....
+        #.#..
+        #....
+        #....
+    ");
+    image.invert();
+    assert_image(&image, "
+        .###.
+        .####
+        .#.##
+        .####
+        .####
+    ");
+    image.fill();
+    assert_image(&image, &AsciiArt(&BitImage::filled()).to_string());
+    image.clear();
+    assert_image(&image, &AsciiArt(&BitImage::blank()).to_string());
+}
+
+#[test]
+#[should_panic(expected = "x out of range")]
+fn test_bit_pixel_out_of_range() {
+    BitImage::filled().pixel(5, 0);
+}
+
+#[test]
+#[should_panic(expected = "x out of range")]
+fn test_bit_set_pixel_out_of_range() {
+    BitImage::blank().set_pixel(5, 0, true);
+}
+
+#[test]
+fn test_conversions() {
+    assert_image(&HEART, "
+        .#.#.
+        #.#.#
+        #...#
+        .#.#.
+        ..#..
+    ");
+    assert_image(&GreyscaleImage::from(HEART), "
+        .9.9.
+        9.9.9
+        9...9
+        .9.9.
+        ..9..
+    ");
+    assert_image(&BitImage::from_greyscale_image(&GREY_HEART, 1), "
+        .#.#.
+        #####
+        #####
+        .###.
+        ..#..
+    ");
+}
+
+const ARROW: BitImage = BitImage::new(&[
+    [0, 0, 1, 0, 0],
+    [0, 1, 1, 1, 0],
+    [1, 0, 1, 0, 1],
+    [0, 0, 1, 0, 0],
+    [0, 0, 1, 1, 0],
+]);
+
+#[test]
+fn test_transforms() {
+    let cases = [
+        (Transform::Identity, "
+            ..#..
+            .###.
+            #.#.#
+            ..#..
+            ..##.
+        "),
+        (Transform::Rotate90, "
+            ..#..
+            ...#.
+            #####
+            #..#.
+            ..#..
+        "),
+        (Transform::Rotate180, "
+            .##..
+            ..#..
+            #.#.#
+            .###.
+            ..#..
+        "),
+        (Transform::Rotate270, "
+            ..#..
+            .#..#
+            #####
+            .#...
+            ..#..
+        "),
+        (Transform::FlipHorizontal, "
+            ..#..
+            .###.
+            #.#.#
+            ..#..
+            .##..
+        "),
+        (Transform::FlipVertical, "
+            ..##.
+            ..#..
+            #.#.#
+            .###.
+            ..#..
+        "),
+        (Transform::Transpose, "
+            ..#..
+            .#...
+            #####
+            .#..#
+            ..#..
+        "),
+        (Transform::AntiTranspose, "
+            ..#..
+            #..#.
+            #####
+            ...#.
+            ..#..
+        "),
+    ];
+    let grey = GreyscaleImage::from(ARROW);
+    for (transform, expected) in cases.iter() {
+        assert_image(&ARROW.transformed(*transform), expected);
+        assert_image(&grey.transformed(*transform), expected);
+        assert_image(&Transformed::new(&ARROW, *transform), expected);
+    }
+}
+
+#[test]
+fn test_shifts() {
+    assert_image(&ARROW.shifted(1, -2, false), "
+        .#.#.
+        ...#.
+        ...##
+        .....
+        .....
+    ");
+    assert_image(&GreyscaleImage::from(ARROW).shifted(-2, 1, 3), "
+        33333
+        #..33
+        ##.33
+        #.#33
+        #..33
+    ");
+    assert_image(&Shifted::new(&ARROW, 5, 0, 1), "
+        11111
+        11111
+        11111
+        11111
+        11111
+    ");
+}
@@ -0,0 +1,46 @@
+use super::*;
+use crate::graphics::ascii_art::AsciiArt;
+use crate::graphics::transform::Transform;
+
+fn lit_pixels(image: &BitImage) -> usize {
+    (0..5).map(|y| (0..5).filter(|&x| image.pixel(x, y)).count()).sum()
+}
+
+#[test]
+fn test_heart() {
+    assert_eq!(AsciiArt(&HEART).to_string(), "\
+.9.9.
+99999
+99999
+.999.
+..9..");
+}
+
+#[test]
+fn test_all_clocks() {
+    assert_eq!(ALL_CLOCKS.len(), 12);
+    for clock in ALL_CLOCKS {
+        assert_eq!(lit_pixels(clock), 3);
+        assert!(clock.pixel(2, 2));
+    }
+    // each hand is the one three hours earlier, rotated
+    for hour in 0..12 {
+        let rotated = ALL_CLOCKS[hour].transformed(Transform::Rotate90);
+        assert_eq!(
+            AsciiArt(&rotated).to_string(),
+            AsciiArt(&ALL_CLOCKS[(hour + 3) % 12]).to_string(),
+        );
+    }
+}
+
+#[test]
+fn test_all_arrows() {
+    assert_eq!(ALL_ARROWS.len(), 8);
+    for direction in 0..8 {
+        let rotated = ALL_ARROWS[direction].transformed(Transform::Rotate90);
+        assert_eq!(
+            AsciiArt(&rotated).to_string(),
+            AsciiArt(&ALL_ARROWS[(direction + 2) % 8]).to_string(),
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use super::*;
+use crate::graphics::image::{BitImage, GreyscaleImage};
+use crate::graphics::test_support::assert_image;
+
+const HEART: GreyscaleImage = crate::greyscale_image!("09090:99999:99999:09990:00900");
+
+const TICK: BitImage = crate::bit_image!("
+    ....#
+    ...#.
+    #.#..
+    .#...
+    .....
+");
+
+#[test]
+fn test_micropython_format() {
+    assert_image(&HEART, "
+        .9.9.
+        99999
+        99999
+        .999.
+        ..9..
+    ");
+    assert_image(&greyscale("01234:56789:.....:#####:00000:"), "
+        .1234
+        56789
+        .....
+        99999
+        .....
+    ");
+}
+
+#[test]
+fn test_ascii_art_format() {
+    assert!(TICK.pixel(4, 0));
+    assert!(TICK.pixel(1, 3));
+    assert!(!TICK.pixel(0, 0));
+    assert_image(&TICK, "
+        ....9
+        ...9.
+        9.9..
+        .9...
+        .....
+    ");
+}
+
+#[test]
+fn test_bits() {
+    let image = bits("10000:09000:00#00:000.0:00000");
+    assert!(image.pixel(0, 0));
+    assert!(image.pixel(1, 1));
+    assert!(image.pixel(2, 2));
+    assert!(!image.pixel(3, 3));
+}
+
+#[test]
+#[should_panic(expected = "off or on")]
+fn test_bits_bad_brightness() {
+    bits("00000:00000:00500:00000:00000");
+}
+
+#[test]
+#[should_panic(expected = "fewer than 5 rows")]
+fn test_too_few_rows() {
+    greyscale("00000:00000:00000:00000");
+}
+
+#[test]
+#[should_panic(expected = "more than 5 rows")]
+fn test_too_many_rows() {
+    greyscale("00000:00000:00000:00000:00000:00000");
+}
+
+#[test]
+#[should_panic(expected = "5 pixels wide")]
+fn test_short_row() {
+    greyscale("00000:0000:00000:00000:00000");
+}
+
+#[test]
+#[should_panic(expected = "5 pixels wide")]
+fn test_long_row() {
+    greyscale("00000:000000:00000:00000:00000");
+}
+
+#[test]
+#[should_panic(expected = "unexpected character")]
+fn test_bad_glyph() {
+    greyscale("00000:00x00:00000:00000:00000");
+}
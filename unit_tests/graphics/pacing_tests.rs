@@ -0,0 +1,114 @@
+use super::*;
+
+// An animation which counts its ticks, finishing after `length`.
+#[derive(Debug)]
+struct Counter {
+    count: usize,
+    length: usize,
+    // calls to tick(), including those after finishing
+    calls: usize,
+}
+
+impl Animate for Counter {
+
+    fn is_finished(&self) -> bool {
+        self.count == self.length
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+    }
+
+    fn tick(&mut self) {
+        self.calls += 1;
+        if !self.is_finished() {self.count += 1}
+    }
+
+}
+
+fn counter(length: usize) -> Paced<Counter> {
+    Paced::new(Counter {count: 0, length, calls: 0})
+}
+
+// Ticks `paced` the specified number of times, returning the values of
+// advanced() after each tick.
+fn run(paced: &mut Paced<Counter>, ticks: usize) -> Vec<bool> {
+    (0..ticks).map(|_| {paced.tick(); paced.advanced()}).collect()
+}
+
+#[test]
+fn test_full_speed() {
+    let mut paced = counter(3);
+    assert_eq!(run(&mut paced, 4), [true, true, true, false]);
+    assert!(paced.is_finished());
+}
+
+#[test]
+fn test_divider() {
+    let mut paced = counter(10);
+    paced.set_divider(3);
+    assert_eq!(run(&mut paced, 6), [false, false, true, false, false, true]);
+    assert_eq!(paced.inner().count, 2);
+}
+
+#[test]
+fn test_fractional_speed() {
+    let mut paced = counter(10);
+    paced.set_speed(2, 5);
+    assert_eq!(run(&mut paced, 5), [false, false, true, false, true]);
+    assert_eq!(paced.inner().count, 2);
+}
+
+#[test]
+fn test_fast_speed() {
+    let mut paced = counter(10);
+    paced.set_speed(3, 1);
+    paced.tick();
+    assert!(paced.advanced());
+    assert_eq!(paced.inner().count, 3);
+}
+
+#[test]
+fn test_fast_speed_finishes() {
+    let mut paced = counter(2);
+    paced.set_speed(3, 1);
+    paced.tick();
+    assert!(paced.advanced());
+    assert!(paced.is_finished());
+    assert_eq!(paced.inner().count, 2);
+    assert_eq!(paced.inner().calls, 2);
+    paced.tick();
+    assert!(!paced.advanced());
+    assert_eq!(paced.inner().calls, 2);
+}
+
+#[test]
+fn test_change_speed() {
+    let mut paced = counter(10);
+    paced.set_divider(4);
+    run(&mut paced, 6);
+    assert_eq!(paced.inner().count, 1);
+    // halfway towards the next step; stays halfway at the new speed
+    paced.set_divider(2);
+    assert_eq!(run(&mut paced, 2), [true, false]);
+    assert_eq!(paced.inner().count, 2);
+    assert_eq!(paced.speed(), (1, 2));
+}
+
+#[test]
+fn test_reset() {
+    let mut paced = counter(10);
+    paced.set_divider(2);
+    run(&mut paced, 3);
+    paced.reset();
+    assert!(!paced.advanced());
+    assert_eq!(paced.inner().count, 0);
+    assert_eq!(run(&mut paced, 2), [false, true]);
+    assert_eq!(paced.into_inner().count, 1);
+}
+
+#[test]
+#[should_panic]
+fn test_zero_ticks() {
+    counter(1).set_speed(1, 0);
+}
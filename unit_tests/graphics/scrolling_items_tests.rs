@@ -0,0 +1,139 @@
+use super::*;
+use crate::graphics::ascii_art::AsciiArt;
+use crate::graphics::test_support::{assert_image, count_frames};
+use crate::graphics::scrolling::{Animate, Direction};
+
+const DOT: BitImage = BitImage::new(&[
+    [0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0],
+    [0, 0, 1, 0, 0],
+    [0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0],
+]);
+
+const GREY: GreyscaleImage = GreyscaleImage::new(&[
+    [0, 0, 0, 0, 0],
+    [0, 3, 0, 0, 0],
+    [0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0],
+]);
+
+static ITEMS: [Item; 5] = [
+    Item::Text("Hé"),
+    Item::Bits(&DOT),
+    Item::Spacer(3),
+    Item::Greyscale(&GREY),
+    Item::Text("!"),
+];
+
+#[test]
+fn test_subimages() {
+    let mut scroller = ScrollingItems::default();
+    scroller.set_items(&ITEMS);
+    assert_eq!(scroller.length(), 6);
+    assert_image(scroller.subimage(1), &AsciiArt(Pendolino.character('é')).to_string());
+    assert_image(scroller.subimage(2), "
+        .....
+        .....
+        ..9..
+        .....
+        .....
+    ");
+    assert_image(scroller.subimage(3), "
+        .....
+        .....
+        .....
+        .....
+        .....
+    ");
+    assert_image(scroller.subimage(4), "
+        .....
+        .3...
+        .....
+        .....
+        .....
+    ");
+    assert_image(scroller.subimage(5), &AsciiArt(Pendolino.character('!')).to_string());
+}
+
+#[test]
+#[should_panic]
+fn test_subimage_out_of_range() {
+    let mut scroller = ScrollingItems::default();
+    scroller.set_items(&ITEMS);
+    scroller.subimage(6);
+}
+
+#[test]
+fn test_cursor() {
+    static ITEMS: [Item; 5] = [
+        Item::Text(""), Item::Text("é"), Item::Bits(&DOT), Item::Text(""), Item::Text("°A"),
+    ];
+    let mut scroller = ScrollingItems::default();
+    scroller.set_items(&ITEMS);
+    for _ in 0..15 {
+        scroller.tick();
+    }
+    // the cursor has moved past 'é' and the dot to '°'
+    assert_eq!(scroller.state().cursor_index(), 2);
+    assert_eq!(scroller.state().cursor_position(), (4, 0));
+    assert_image(scroller.subimage(3), &AsciiArt(Pendolino.character('A')).to_string());
+    assert_image(scroller.subimage(0), &AsciiArt(Pendolino.character('é')).to_string());
+    assert_image(&scroller, &AsciiArt(Pendolino.character('°')).to_string());
+}
+
+#[test]
+fn test_fixed_width() {
+    let mut scroller = ScrollingItems::default();
+    scroller.set_items(&ITEMS);
+    // five 5-column items and a 3-column spacer, plus lead-in and lead-out
+    assert_eq!(count_frames(&mut scroller), 5 + 25 + 3 + 5 - 4);
+}
+
+#[test]
+fn test_proportional() {
+    let mut scroller = ScrollingItems::default();
+    scroller.set_items(&ITEMS[1..4]);
+    scroller.set_proportional(true);
+    // the dot, a gap, the spacer, a gap, then the grey pixel
+    for _ in 0..5 {
+        scroller.tick();
+    }
+    assert_image(&scroller, "
+        .....
+        .....
+        9....
+        .....
+        .....
+    ");
+    for _ in 0..2 {
+        scroller.tick();
+    }
+    assert_image(&scroller, "
+        .....
+        ....3
+        .....
+        .....
+        .....
+    ");
+    scroller.reset();
+    assert_eq!(count_frames(&mut scroller), 5 + 7 + 5 - 4);
+}
+
+#[test]
+fn test_direction() {
+    let mut scroller = ScrollingItems::default();
+    scroller.set_items(&ITEMS[1..2]);
+    scroller.state_mut().set_direction(Direction::Up);
+    for _ in 0..5 {
+        scroller.tick();
+    }
+    assert_image(&scroller, "
+        .....
+        .....
+        ..9..
+        .....
+        .....
+    ");
+}
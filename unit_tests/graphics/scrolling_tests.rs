@@ -0,0 +1,310 @@
+use super::*;
+use crate::graphics::test_support::{assert_image, count_frames};
+use crate::graphics::image::BitImage;
+
+static IMAGES: [BitImage; 1] = [BitImage::new(&[
+    [1, 1, 1, 1, 1],
+    [1, 0, 0, 0, 0],
+    [1, 1, 1, 0, 0],
+    [1, 0, 0, 0, 0],
+    [1, 0, 0, 0, 0],
+])];
+
+fn scroller(direction: Direction) -> ScrollingImages<BitImage> {
+    let mut scroller = ScrollingImages::default();
+    scroller.set_images(&IMAGES);
+    scroller.state_mut().set_direction(direction);
+    scroller
+}
+
+#[test]
+fn test_default_direction() {
+    let scroller: ScrollingImages<BitImage> = ScrollingImages::default();
+    assert_eq!(scroller.state().direction(), Direction::Left);
+}
+
+#[test]
+fn test_left() {
+    let mut scroller = scroller(Direction::Left);
+    scroller.tick();
+    scroller.tick();
+    assert_image(&scroller, "
+        ...##
+        ...#.
+        ...##
+        ...#.
+        ...#.
+    ");
+}
+
+#[test]
+fn test_right() {
+    let mut scroller = scroller(Direction::Right);
+    scroller.tick();
+    scroller.tick();
+    assert_image(&scroller, "
+        ##...
+        .....
+        .....
+        .....
+        .....
+    ");
+    scroller.tick();
+    scroller.tick();
+    assert_image(&scroller, "
+        ####.
+        .....
+        ##...
+        .....
+        .....
+    ");
+}
+
+#[test]
+fn test_up() {
+    let mut scroller = scroller(Direction::Up);
+    scroller.tick();
+    scroller.tick();
+    assert_image(&scroller, "
+        .....
+        .....
+        .....
+        #####
+        #....
+    ");
+    for _ in 0..5 {
+        scroller.tick();
+    }
+    assert_image(&scroller, "
+        ###..
+        #....
+        #....
+        .....
+        .....
+    ");
+}
+
+#[test]
+fn test_down() {
+    let mut scroller = scroller(Direction::Down);
+    scroller.tick();
+    scroller.tick();
+    assert_image(&scroller, "
+        #....
+        #....
+        .....
+        .....
+        .....
+    ");
+    for _ in 0..5 {
+        scroller.tick();
+    }
+    assert_image(&scroller, "
+        .....
+        .....
+        #####
+        #....
+        ###..
+    ");
+    for _ in 0..4 {
+        scroller.tick();
+    }
+    assert!(scroller.is_finished());
+}
+
+static TWO_IMAGES: [BitImage; 2] = [IMAGES[0], IMAGES[0]];
+
+#[test]
+fn test_default_layout() {
+    let mut scroller = ScrollingImages::default();
+    scroller.set_images(&TWO_IMAGES);
+    assert_eq!(count_frames(&mut scroller), 16);
+    assert_image(&scroller, "
+        .....
+        .....
+        .....
+        .....
+        .....
+    ");
+    let mut scroller: ScrollingImages<BitImage> = ScrollingImages::default();
+    assert!(!scroller.is_finished());
+    assert_eq!(count_frames(&mut scroller), 6);
+}
+
+#[test]
+fn test_gap() {
+    let mut scroller = ScrollingImages::default();
+    scroller.set_images(&TWO_IMAGES);
+    scroller.state_mut().set_gap(2);
+    for _ in 0..9 {
+        scroller.tick();
+    }
+    assert_image(&scroller, "
+        #..##
+        ...#.
+        ...##
+        ...#.
+        ...#.
+    ");
+    scroller.reset();
+    assert_eq!(count_frames(&mut scroller), 18);
+}
+
+#[test]
+fn test_lead_in_and_lead_out() {
+    let mut scroller = ScrollingImages::default();
+    scroller.set_images(&TWO_IMAGES);
+    scroller.state_mut().set_lead_in(0);
+    scroller.state_mut().set_lead_out(0);
+    assert_image(&scroller, "
+        #####
+        #....
+        ###..
+        #....
+        #....
+    ");
+    assert_eq!(count_frames(&mut scroller), 6);
+    assert_image(&scroller, "
+        #####
+        #....
+        ###..
+        #....
+        #....
+    ");
+}
+
+#[test]
+fn test_marquee_repeats() {
+    let mut scroller = scroller(Direction::Left);
+    scroller.state_mut().set_marquee(2, Some(3));
+    assert_eq!(scroller.state().marquee_gap(), Some(2));
+    assert_eq!(scroller.state().repeats(), Some(3));
+    // three passes of 5 columns with two 2-column gaps, plus lead-in and
+    // lead-out
+    assert_eq!(count_frames(&mut scroller), 25);
+    assert_eq!(scroller.state().passes(), 2);
+    scroller.reset();
+    assert_eq!(scroller.state().passes(), 0);
+    scroller.state_mut().set_marquee(2, Some(1));
+    assert_eq!(count_frames(&mut scroller), 11);
+}
+
+#[test]
+fn test_marquee_unlimited() {
+    let mut scroller = scroller(Direction::Left);
+    scroller.state_mut().set_marquee(2, None);
+    for _ in 0..8 {
+        scroller.tick();
+    }
+    assert_image(&scroller, "
+        ##..#
+        ....#
+        ....#
+        ....#
+        ....#
+    ");
+    for _ in 0..700 {
+        scroller.tick();
+    }
+    assert!(!scroller.is_finished());
+    assert_eq!(scroller.state().passes(), 100);
+    assert_image(&scroller, "
+        ##..#
+        ....#
+        ....#
+        ....#
+        ....#
+    ");
+    scroller.state_mut().clear_marquee();
+    // the rest of the current pass, and the lead-out
+    assert_eq!(count_frames(&mut scroller), 3);
+}
+
+static NARROW: [BitImage; 1] = [BitImage::new(&[
+    [1, 1, 0, 0, 0],
+    [1, 0, 0, 0, 0],
+    [1, 1, 0, 0, 0],
+    [1, 0, 0, 0, 0],
+    [1, 0, 0, 0, 0],
+])];
+
+#[test]
+fn test_marquee_narrow() {
+    // the display shows more than two passes at once
+    let mut scroller = ScrollingImages::default();
+    scroller.set_images(&NARROW);
+    scroller.state_mut().set_proportional(true);
+    scroller.state_mut().set_lead_in(0);
+    scroller.state_mut().set_marquee(0, None);
+    scroller.tick();
+    assert_image(&scroller, "
+        #####
+        .#.#.
+        #####
+        .#.#.
+        .#.#.
+    ");
+    scroller.tick();
+    assert_eq!(scroller.state().passes(), 1);
+    assert_image(&scroller, "
+        #####
+        #.#.#
+        #####
+        #.#.#
+        #.#.#
+    ");
+}
+
+#[test]
+fn test_smooth() {
+    let mut scroller = scroller(Direction::Left);
+    scroller.state_mut().set_lead_in(4);
+    scroller.state_mut().set_substeps(2);
+    assert_eq!(scroller.state().substeps(), 2);
+    assert_image(&scroller, "
+        ....9
+        ....9
+        ....9
+        ....9
+        ....9
+    ");
+    scroller.tick();
+    assert_image(&scroller, "
+        ...49
+        ...45
+        ...49
+        ...45
+        ...45
+    ");
+    scroller.tick();
+    assert_image(&scroller, "
+        ...99
+        ...9.
+        ...99
+        ...9.
+        ...9.
+    ");
+    scroller.reset();
+    assert_eq!(count_frames(&mut scroller), 2 * 9 + 1);
+}
+
+#[test]
+fn test_smooth_change_substeps() {
+    let mut scroller = scroller(Direction::Up);
+    scroller.state_mut().set_substeps(4);
+    for _ in 0..6 {
+        scroller.tick();
+    }
+    // halfway between rows; stays halfway
+    scroller.state_mut().set_substeps(2);
+    scroller.tick();
+    assert_image(&scroller, "
+        .....
+        .....
+        .....
+        99999
+        9....
+    ");
+    scroller.state_mut().set_substeps(0);
+    assert_eq!(scroller.state().substeps(), 1);
+}
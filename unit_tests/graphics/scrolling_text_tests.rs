@@ -0,0 +1,209 @@
+use super::*;
+use crate::graphics::ascii_art::AsciiArt;
+use crate::graphics::test_support::{assert_image, count_frames};
+use crate::graphics::font::{Font, Pendolino};
+use crate::graphics::scrolling::PROPORTIONAL_BLANK_WIDTH;
+use core::ptr;
+use core::fmt::Write;
+
+#[test]
+fn test_fixed_width() {
+    let mut scroller = ScrollingStaticText::default();
+    scroller.set_message(b"I I");
+    assert_eq!(count_frames(&mut scroller), 21);
+}
+
+#[test]
+fn test_proportional() {
+    let mut scroller = ScrollingStaticText::default();
+    scroller.set_message(b"II");
+    scroller.set_proportional(true);
+    for _ in 0..5 {
+        scroller.tick();
+    }
+    assert_image(&scroller, "
+        ###.#
+        .#...
+        .#...
+        .#...
+        ###.#
+    ");
+    scroller.reset();
+    assert_eq!(count_frames(&mut scroller), 13);
+}
+
+#[test]
+fn test_proportional_space() {
+    let mut scroller = ScrollingBufferedText::default();
+    scroller.set_message(b"I I");
+    scroller.set_proportional(true);
+    assert_eq!(count_frames(&mut scroller), 3 + 1 + PROPORTIONAL_BLANK_WIDTH + 1 + 3 + 6);
+    scroller.set_proportional(false);
+    assert_eq!(scroller.state().gap(), 0);
+    assert_eq!(count_frames(&mut scroller), 21);
+}
+
+#[test]
+fn test_proportional_long_message() {
+    // Ticking via Animate moves the state's cursor; ticking the state
+    // directly doesn't. Both must render the same frames.
+    let message = b"Hello, world! The quick brown fox.";
+    let mut scroller = ScrollingStaticText::default();
+    scroller.set_message(message);
+    scroller.set_proportional(true);
+    let mut uncached = scroller;
+    while !scroller.is_finished() {
+        assert_eq!(AsciiArt(&scroller).to_string(), AsciiArt(&uncached).to_string());
+        scroller.tick();
+        uncached.state_mut().tick();
+    }
+    assert!(uncached.is_finished());
+}
+
+#[test]
+fn test_utf8() {
+    let mut scroller = ScrollingStaticText::default();
+    scroller.set_message("é°");
+    assert_eq!(scroller.length(), 2);
+    for _ in 0..5 {
+        scroller.tick();
+    }
+    assert_image(&scroller, "
+        ...#.
+        .##..
+        ####.
+        #....
+        .###.
+    ");
+    assert_eq!(count_frames(&mut scroller), 11);
+}
+
+#[test]
+fn test_utf8_buffered() {
+    let mut scroller = ScrollingBufferedText::default();
+    scroller.set_message("12 €");
+    assert_eq!(scroller.length(), 4);
+    assert!(ptr::eq(scroller.subimage(3), Pendolino.character('€')));
+}
+
+#[test]
+fn test_utf8_cursor() {
+    let mut scroller = ScrollingBufferedText::default();
+    scroller.set_message("é°€A");
+    for _ in 0..15 {
+        scroller.tick();
+    }
+    // the cursor has moved past 'é' and '°'
+    assert_eq!(scroller.state().cursor_index(), 2);
+    assert_eq!(scroller.state().cursor_position(), (4, 0));
+    assert!(ptr::eq(scroller.subimage(3), Pendolino.character('A')));
+    assert!(ptr::eq(scroller.subimage(0), Pendolino.character('é')));
+    // completing a truncated character at the end moves the cursor back
+    scroller.set_message(b"\xE2\x82");
+    for _ in 0..10 {
+        scroller.tick();
+    }
+    assert_eq!(scroller.state().cursor_index(), 1);
+    scroller.append(b"\xAC").unwrap();
+    assert_eq!(scroller.length(), 1);
+    assert_eq!(scroller.state().cursor_index(), 0);
+    assert!(ptr::eq(scroller.subimage(0), Pendolino.character('€')));
+}
+
+#[test]
+fn test_invalid_utf8() {
+    let mut scroller = ScrollingStaticText::default();
+    // a lone continuation byte, and a truncated two-byte sequence
+    scroller.set_message(b"a\x80b\xC3");
+    assert_eq!(scroller.length(), 4);
+    assert!(ptr::eq(scroller.subimage(1), Pendolino.fallback()));
+    assert!(ptr::eq(scroller.subimage(2), Pendolino.character('b')));
+    assert!(ptr::eq(scroller.subimage(3), Pendolino.fallback()));
+}
+
+#[test]
+fn test_write() {
+    let mut scroller = ScrollingBufferedText::default();
+    scroller.set_message("T=");
+    write!(scroller, "{}C", -4).unwrap();
+    assert_eq!(scroller.length(), 5);
+    assert!(ptr::eq(scroller.subimage(2), Pendolino.character('-')));
+    assert!(ptr::eq(scroller.subimage(4), Pendolino.character('C')));
+    scroller.clear_message();
+    assert_eq!(scroller.length(), 0);
+    write!(scroller, "{:03}", 7).unwrap();
+    assert!(ptr::eq(scroller.subimage(1), Pendolino.character('0')));
+    assert!(ptr::eq(scroller.subimage(2), Pendolino.character('7')));
+}
+
+#[test]
+fn test_write_truncates() {
+    let mut scroller = ScrollingBufferedText::default();
+    scroller.set_message(&[b'x'; 126][..]);
+    // '€' is three bytes, so doesn't fit in the remaining two
+    assert!(write!(scroller, "a€b").is_err());
+    assert_eq!(scroller.length(), 127);
+    assert!(ptr::eq(scroller.subimage(126), Pendolino.character('a')));
+    assert!(write!(scroller, "c").is_ok());
+    assert!(write!(scroller, "d").is_err());
+    assert_eq!(scroller.length(), 128);
+}
+
+#[test]
+fn test_capacity() {
+    let mut scroller: ScrollingBufferedText<Pendolino, 4> =
+        ScrollingBufferedText::with_font(Pendolino);
+    assert_eq!(scroller.capacity(), 4);
+    assert_eq!(ScrollingBufferedText::default().capacity(), DEFAULT_CAPACITY);
+    scroller.set_message("abcd");
+    assert_eq!(scroller.length(), 4);
+    scroller.tick();
+    let frame = AsciiArt(&scroller).to_string();
+    assert_eq!(
+        scroller.try_set_message("abcde"),
+        Err(MessageTooLong {length: 5, capacity: 4}),
+    );
+    assert_eq!(scroller.length(), 4);
+    assert_eq!(AsciiArt(&scroller).to_string(), frame);
+    assert_eq!(scroller.try_set_message("é"), Ok(()));
+    assert_eq!(scroller.length(), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_set_message_too_long() {
+    let mut scroller: ScrollingBufferedText<Pendolino, 4> =
+        ScrollingBufferedText::with_font(Pendolino);
+    scroller.set_message("abcde");
+}
+
+#[test]
+fn test_append() {
+    let mut scroller: ScrollingBufferedText<Pendolino, 6> =
+        ScrollingBufferedText::with_font(Pendolino);
+    scroller.set_message("ab");
+    scroller.tick();
+    let frame = AsciiArt(&scroller).to_string();
+    assert_eq!(scroller.append("c€"), Ok(()));
+    assert_eq!(scroller.length(), 4);
+    assert!(ptr::eq(scroller.subimage(3), Pendolino.character('€')));
+    // not reset
+    assert_eq!(AsciiArt(&scroller).to_string(), frame);
+    assert_eq!(scroller.append(b"d"), Err(MessageTooLong {length: 7, capacity: 6}));
+    assert_eq!(scroller.length(), 4);
+}
+
+#[test]
+fn test_marquee() {
+    let mut scroller = ScrollingStaticText::default();
+    scroller.set_message("Hi");
+    scroller.state_mut().set_marquee(3, Some(2));
+    assert_eq!(count_frames(&mut scroller), 29);
+    let mut scroller = ScrollingBufferedText::default();
+    scroller.set_message("Hi");
+    scroller.state_mut().set_marquee(3, None);
+    for _ in 0..1000 {
+        scroller.tick();
+    }
+    assert!(!scroller.is_finished());
+}
@@ -0,0 +1,92 @@
+use super::*;
+use crate::graphics::image::BitImage;
+
+const A: BitImage = crate::bit_image!("#....:.....:.....:.....:.....");
+const B: BitImage = crate::bit_image!(".#...:.....:.....:.....:.....");
+const C: BitImage = crate::bit_image!("..#..:.....:.....:.....:.....");
+
+static FRAMES: [(&BitImage, usize); 3] = [(&A, 2), (&B, 1), (&C, 3)];
+
+// Returns the column lit in the current frame of each of the next `ticks`
+// steps.
+fn play(sequence: &mut Sequence<&'static BitImage>, steps: usize) -> Vec<usize> {
+    let mut shown = Vec::new();
+    for _ in 0..steps {
+        shown.push((0..5).position(|x| sequence.brightness_at(x, 0) != 0).unwrap());
+        sequence.tick();
+    }
+    shown
+}
+
+#[test]
+fn test_once() {
+    let mut sequence = Sequence::default();
+    sequence.set_frames(&FRAMES);
+    assert_eq!(sequence.total_ticks(), 6);
+    assert_eq!(play(&mut sequence, 8), [0, 0, 1, 2, 2, 2, 2, 2]);
+    assert!(sequence.is_finished());
+    assert_eq!(sequence.position(), 5);
+    sequence.reset();
+    assert!(!sequence.is_finished());
+    assert_eq!(sequence.position(), 0);
+}
+
+#[test]
+fn test_loop() {
+    let mut sequence = Sequence::default();
+    sequence.set_frames(&FRAMES);
+    sequence.set_mode(LoopMode::Loop);
+    assert_eq!(play(&mut sequence, 9), [0, 0, 1, 2, 2, 2, 0, 0, 1]);
+    assert!(!sequence.is_finished());
+}
+
+#[test]
+fn test_ping_pong() {
+    let mut sequence = Sequence::default();
+    sequence.set_frames(&FRAMES);
+    sequence.set_mode(LoopMode::PingPong);
+    assert_eq!(
+        play(&mut sequence, 14),
+        [0, 0, 1, 2, 2, 2, 1, 0, 0, 1, 2, 2, 2, 1],
+    );
+    // now playing B backwards
+    assert_eq!(sequence.keyframe(), 0);
+    assert_eq!(sequence.position(), 1);
+    sequence.tick();
+    assert_eq!(sequence.position(), 0);
+}
+
+#[test]
+fn test_pause() {
+    let mut sequence = Sequence::default();
+    sequence.set_frames(&FRAMES);
+    sequence.tick();
+    sequence.pause();
+    assert!(sequence.is_paused());
+    assert_eq!(play(&mut sequence, 3), [0, 0, 0]);
+    assert_eq!(sequence.position(), 1);
+    sequence.resume();
+    assert_eq!(play(&mut sequence, 2), [0, 1]);
+}
+
+#[test]
+fn test_seek() {
+    let mut sequence = Sequence::default();
+    sequence.set_frames(&FRAMES);
+    sequence.seek(3);
+    assert_eq!(sequence.keyframe(), 2);
+    assert_eq!(sequence.position(), 3);
+    assert_eq!(play(&mut sequence, 3), [2, 2, 2]);
+    sequence.seek(100);
+    assert_eq!(sequence.position(), 5);
+    assert!(sequence.is_finished());
+}
+
+#[test]
+fn test_empty() {
+    let mut sequence: Sequence<&'static BitImage> = Sequence::default();
+    assert!(sequence.is_finished());
+    sequence.tick();
+    assert_eq!(sequence.position(), 0);
+    assert_eq!(sequence.brightness_at(0, 0), 0);
+}
@@ -0,0 +1,160 @@
+use super::*;
+use crate::graphics::ascii_art::AsciiArt;
+use crate::graphics::test_support::assert_image;
+use crate::graphics::image::GreyscaleImage;
+
+fn run<A: Render, B: Render>(transition: &mut Transition<A, B>, ticks: usize) {
+    for _ in 0..ticks {transition.tick()}
+}
+
+#[test]
+fn test_finishes() {
+    let (from, to) = (GreyscaleImage::filled(9), GreyscaleImage::filled(0));
+    let mut transition = Transition::new(&from, &to, Effect::CrossFade, 3);
+    assert_image(&transition, "
+        99999
+        99999
+        99999
+        99999
+        99999
+    ");
+    run(&mut transition, 2);
+    assert!(!transition.is_finished());
+    transition.tick();
+    assert!(transition.is_finished());
+    assert_image(&transition, "
+        .....
+        .....
+        .....
+        .....
+        .....
+    ");
+    transition.tick();
+    assert_eq!(transition.elapsed(), 3);
+    transition.reset();
+    assert_eq!(transition.elapsed(), 0);
+    assert_image(&transition, "
+        99999
+        99999
+        99999
+        99999
+        99999
+    ");
+}
+
+#[test]
+fn test_zero_duration() {
+    let (from, to) = (GreyscaleImage::filled(9), GreyscaleImage::filled(4));
+    let transition = Transition::new(&from, &to, Effect::Zoom, 0);
+    assert!(transition.is_finished());
+    assert_image(&transition, "
+        44444
+        44444
+        44444
+        44444
+        44444
+    ");
+}
+
+#[test]
+fn test_cross_fade() {
+    let (from, to) = (GreyscaleImage::filled(9), GreyscaleImage::filled(1));
+    let mut transition = Transition::new(&from, &to, Effect::CrossFade, 4);
+    let levels: Vec<u8> = (0..5).map(|_| {
+        let level = transition.brightness_at(2, 2);
+        transition.tick();
+        level
+    }).collect();
+    assert_eq!(levels, [9, 7, 5, 3, 1]);
+}
+
+#[test]
+fn test_fade() {
+    let (from, to) = (GreyscaleImage::filled(8), GreyscaleImage::filled(6));
+    let mut transition = Transition::new(&from, &to, Effect::Fade, 4);
+    let levels: Vec<u8> = (0..5).map(|_| {
+        let level = transition.brightness_at(0, 0);
+        transition.tick();
+        level
+    }).collect();
+    assert_eq!(levels, [8, 4, 0, 3, 6]);
+}
+
+#[test]
+fn test_wipe() {
+    let (from, to) = (GreyscaleImage::filled(0), GreyscaleImage::filled(9));
+    let mut transition = Transition::new(&from, &to, Effect::Wipe(Direction::Left), 10);
+    transition.tick();
+    assert_image(&transition, "
+        ....4
+        ....4
+        ....4
+        ....4
+        ....4
+    ");
+    run(&mut transition, 4);
+    assert_image(&transition, "
+        ..499
+        ..499
+        ..499
+        ..499
+        ..499
+    ");
+    let mut transition = Transition::new(&from, &to, Effect::Wipe(Direction::Down), 5);
+    run(&mut transition, 2);
+    assert_image(&transition, "
+        99999
+        99999
+        .....
+        .....
+        .....
+    ");
+}
+
+#[test]
+fn test_zoom() {
+    let (from, to) = (GreyscaleImage::filled(9), GreyscaleImage::filled(0));
+    let mut transition = Transition::new(&from, &to, Effect::Zoom, 6);
+    run(&mut transition, 3);
+    assert_image(&transition, "
+        99999
+        95559
+        95.59
+        95559
+        99999
+    ");
+}
+
+#[test]
+fn test_dissolve() {
+    let (from, to) = (GreyscaleImage::filled(0), GreyscaleImage::filled(9));
+    let mut transition = Transition::new(&from, &to, Effect::Dissolve, 25);
+    let mut changed = [false; 25];
+    for tick in 1..=25 {
+        transition.tick();
+        let lit: Vec<usize> = (0..25)
+            .filter(|&i| transition.brightness_at(i % 5, i / 5) == 9)
+            .collect();
+        // exactly one more pixel is fully changed on each tick
+        assert_eq!(lit.len(), tick);
+        for i in lit {changed[i] = true}
+    }
+    assert!(changed.iter().all(|&c| c));
+}
+
+#[test]
+fn test_dissolve_seed() {
+    let ranks = dissolve_ranks(DEFAULT_DISSOLVE_SEED);
+    let mut sorted = ranks;
+    sorted.sort();
+    assert_eq!(sorted.to_vec(), (0..25).collect::<Vec<u8>>());
+    assert_ne!(dissolve_ranks(1), ranks);
+    assert_eq!(dissolve_ranks(0), ranks);
+    let (from, to) = (GreyscaleImage::filled(0), GreyscaleImage::filled(9));
+    let mut a = Transition::new(&from, &to, Effect::Dissolve, 25);
+    let mut b = a;
+    b.set_dissolve_seed(12345);
+    run(&mut a, 10);
+    run(&mut b, 10);
+    assert_ne!(AsciiArt(&a).to_string(), AsciiArt(&b).to_string());
+}